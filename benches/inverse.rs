@@ -0,0 +1,47 @@
+//! Compares the closed-form `Matr4` inverse with the cofactor expansion it
+//! replaced.
+//!
+//! Run with `cargo bench --bench inverse`.
+#![feature(test)]
+extern crate test;
+
+use geometry::matrix::{FromArray, Inverse};
+use geometry::{Cofactor, Determinant, Matr4};
+use test::{Bencher, black_box};
+
+fn transform() -> Matr4 {
+    Matr4::translation(1., -2., 3.) * Matr4::rotation_y_rad(0.3) * Matr4::scaling(2., 1., 0.5)
+}
+// The previous implementation: 16 cofactors, each a 3x3 submatrix expanded
+// into 2x2 determinants, divided by the first-row expansion
+fn cofactor_inverse(m: &Matr4) -> Option<Matr4> {
+    let det: f32 = (0..4).map(|col| m[(0, col)] * m.cofactor(0, col)).sum();
+    if det == 0. {
+        return None;
+    }
+    let array: [f32; 16] = core::array::from_fn(|idx| m.cofactor(idx % 4, idx / 4) / det);
+    Some(Matr4::from_array(array))
+}
+#[bench]
+fn inverse_closed_form(b: &mut Bencher) {
+    let m = transform();
+    b.iter(|| black_box(&m).inverse())
+}
+#[bench]
+fn inverse_cofactor_expansion(b: &mut Bencher) {
+    let m = transform();
+    b.iter(|| cofactor_inverse(black_box(&m)))
+}
+#[bench]
+fn determinant_closed_form(b: &mut Bencher) {
+    let m = transform();
+    b.iter(|| black_box(&m).determinant())
+}
+#[bench]
+fn determinant_cofactor_expansion(b: &mut Bencher) {
+    let m = transform();
+    b.iter(|| {
+        let m = black_box(&m);
+        (0..4).map(|col| m[(0, col)] * m.cofactor(0, col)).sum::<f32>()
+    })
+}
@@ -0,0 +1,160 @@
+//! Axis-aligned bounding boxes.
+
+use core::simd::cmp::SimdPartialOrd;
+use core::simd::num::SimdFloat;
+use core::simd::f32x4;
+
+use crate::matrix::Matr4;
+use crate::ray::Ray;
+use crate::vertex::Vert4;
+
+/// Box between the points `min` and `max`, bounds included. The empty box
+/// has `min` at +∞ and `max` at -∞ so that merging anything into it yields
+/// the other box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aabb {
+    min: Vert4,
+    max: Vert4,
+}
+impl Aabb {
+    /// Orders the corners component-wise, `a` and `b` may be any two
+    /// opposite corners
+    #[inline]
+    pub fn new(a: &Vert4, b: &Vert4) -> Aabb {
+        Aabb::from_simd(a.as_simd().simd_min(*b.as_simd()), a.as_simd().simd_max(*b.as_simd()))
+    }
+    #[inline]
+    pub const fn empty() -> Aabb {
+        Aabb {
+            min: Vert4::point(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+            max: Vert4::point(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+        }
+    }
+    /// The box around everything
+    #[inline]
+    pub const fn infinite() -> Aabb {
+        Aabb {
+            min: Vert4::point(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+            max: Vert4::point(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+        }
+    }
+    /// The smallest box around `points`, empty if there are none
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Vert4>) -> Aabb {
+        points.into_iter().fold(Aabb::empty(), |aabb, point| aabb.merge_point(point))
+    }
+    // Forces w back to 1, whatever the lanes held
+    #[inline]
+    fn from_simd(mut min: f32x4, mut max: f32x4) -> Aabb {
+        min[3] = 1.;
+        max[3] = 1.;
+        Aabb { min: min.into(), max: max.into() }
+    }
+    #[inline]
+    pub fn min(&self) -> &Vert4 {
+        &self.min
+    }
+    #[inline]
+    pub fn max(&self) -> &Vert4 {
+        &self.max
+    }
+    /// Whether `min` is above `max` on some axis
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.as_simd().simd_gt(*self.max.as_simd()).any()
+    }
+    /// The box around both
+    #[inline]
+    pub fn merge(&self, other: &Aabb) -> Aabb {
+        Aabb::from_simd(
+            self.min.as_simd().simd_min(*other.min.as_simd()),
+            self.max.as_simd().simd_max(*other.max.as_simd()),
+        )
+    }
+    /// The box grown to include `point`
+    #[inline]
+    pub fn merge_point(&self, point: &Vert4) -> Aabb {
+        Aabb::from_simd(self.min.as_simd().simd_min(*point.as_simd()), self.max.as_simd().simd_max(*point.as_simd()))
+    }
+    #[inline]
+    pub fn contains(&self, point: &Vert4) -> bool {
+        let p = point.as_simd();
+        (p.simd_ge(*self.min.as_simd()) & p.simd_le(*self.max.as_simd())).all()
+    }
+    /// Whether the two boxes overlap, touching included
+    #[inline]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        (self.min.as_simd().simd_le(*other.max.as_simd()) & other.min.as_simd().simd_le(*self.max.as_simd())).all()
+    }
+    #[inline]
+    pub fn centre(&self) -> Vert4 {
+        ((self.min.as_simd() + self.max.as_simd()) * f32x4::splat(0.5)).into()
+    }
+    /// `max - min`, as a vector
+    #[inline]
+    pub fn extents(&self) -> Vert4 {
+        (self.max.as_simd() - self.min.as_simd()).into()
+    }
+    /// Zero for an empty box
+    #[inline]
+    pub fn surface_area(&self) -> f32 {
+        if self.is_empty() {
+            return 0.;
+        }
+        let e = self.extents();
+        2. * (e.x() * e.y() + e.y() * e.z() + e.z() * e.x())
+    }
+    /// Whether the box is bounded on every axis
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        (self.min.as_simd().is_finite() & self.max.as_simd().is_finite()).all()
+    }
+    /// The box around the 8 transformed corners. A box that is unbounded on
+    /// some axis may end up anywhere once rotated, so it becomes unbounded
+    /// on all of them.
+    pub fn transform(&self, matrix: &Matr4) -> Aabb {
+        if self.is_empty() {
+            return Aabb::empty();
+        }
+        if !self.is_finite() {
+            return Aabb::infinite();
+        }
+        let (min, max) = (&self.min, &self.max);
+        (0..8)
+            .map(|corner| {
+                let x = if corner & 1 == 0 { min.x() } else { max.x() };
+                let y = if corner & 2 == 0 { min.y() } else { max.y() };
+                let z = if corner & 4 == 0 { min.z() } else { max.z() };
+                matrix * Vert4::point(x, y, z)
+            })
+            .fold(Aabb::empty(), |aabb, point| aabb.merge_point(&point))
+    }
+    /// Entry distance of `ray` into the box within `[t_min, t_max]`, if it
+    /// gets there. A ray starting inside enters at `t_min`.
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<f32> {
+        let inv_direction = f32x4::splat(1.) / ray.direction.as_simd();
+        self.slab(ray.origin.as_simd(), &inv_direction, t_min, t_max)
+    }
+    /// The slab test with the reciprocal of the direction already at hand,
+    /// for testing one ray against many boxes
+    #[inline]
+    pub fn slab(&self, origin: &f32x4, inv_direction: &f32x4, t_min: f32, t_max: f32) -> Option<f32> {
+        let t1 = (self.min.as_simd() - origin) * inv_direction;
+        let t2 = (self.max.as_simd() - origin) * inv_direction;
+        // 0·∞ is NaN where a ray parallel to an axis starts on one of its
+        // faces, and in w. min/max return the other operand on NaN, which
+        // opens those slabs to (-∞, ∞), so they never decide the outcome.
+        let (lowest, highest) = (f32x4::splat(f32::NEG_INFINITY), f32x4::splat(f32::INFINITY));
+        let near = t1.simd_max(lowest).simd_min(t2.simd_max(lowest));
+        let far = t1.simd_min(highest).simd_max(t2.simd_min(highest));
+        let enter = near.reduce_max().max(t_min);
+        let exit = far.reduce_min().min(t_max);
+        (enter <= exit).then_some(enter)
+    }
+}
+impl Default for Aabb {
+    #[inline]
+    fn default() -> Self {
+        Aabb::empty()
+    }
+}
@@ -0,0 +1,405 @@
+//! Points and vectors as distinct types.
+//!
+//! Both are backed by a [`Vertex4`], with `w` fixed to `1` for points and `0`
+//! for vectors. The operators only allow the affine combinations:
+//!
+//! - point - point = vector
+//! - point ± vector = point
+//! - vector ± vector = vector
+//!
+//! ```compile_fail
+//! use geometry::affine::Point;
+//! let _ = Point::new(1., 2., 3.) + Point::new(4., 5., 6.);
+//! ```
+
+use core::ops::Neg;
+use core::ops::{Add, AddAssign};
+use core::ops::{Div, DivAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Sub, SubAssign};
+use core::simd::Simd;
+
+use crate::matrix::Matrix4;
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::{Cross, Dot, Mag, Norm, Vertex4};
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq)]
+pub struct Point3<T: Scalar>(Vertex4<T>)
+where
+    Simd<T, 4>: SimdOps<T, 4>;
+pub type Point = Point3<f32>;
+pub type DPoint = Point3<f64>;
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq)]
+pub struct Vector3<T: Scalar>(Vertex4<T>)
+where
+    Simd<T, 4>: SimdOps<T, 4>;
+pub type Vector = Vector3<f32>;
+pub type DVector = Vector3<f64>;
+
+impl<T: Scalar> Point3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    pub const ORIGIN: Self = Self(Vertex4::point(T::ZERO, T::ZERO, T::ZERO));
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self(Vertex4::point(x, y, z))
+    }
+    #[inline]
+    pub fn x(&self) -> T {
+        self.0.x()
+    }
+    #[inline]
+    pub fn y(&self) -> T {
+        self.0.y()
+    }
+    #[inline]
+    pub fn z(&self) -> T {
+        self.0.z()
+    }
+    #[inline]
+    pub const fn as_vertex(&self) -> &Vertex4<T> {
+        &self.0
+    }
+}
+impl<T: Scalar> Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    pub const ZERO: Self = Self(Vertex4::ZERO);
+    pub const X: Self = Self(Vertex4::X);
+    pub const Y: Self = Self(Vertex4::Y);
+    pub const Z: Self = Self(Vertex4::Z);
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self(Vertex4::vector(x, y, z))
+    }
+    #[inline]
+    pub fn x(&self) -> T {
+        self.0.x()
+    }
+    #[inline]
+    pub fn y(&self) -> T {
+        self.0.y()
+    }
+    #[inline]
+    pub fn z(&self) -> T {
+        self.0.z()
+    }
+    #[inline]
+    pub const fn as_vertex(&self) -> &Vertex4<T> {
+        &self.0
+    }
+}
+// Conversion impls---------
+impl<T: Scalar> From<Point3<T>> for Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn from(value: Point3<T>) -> Self {
+        value.0
+    }
+}
+impl<T: Scalar> From<Vector3<T>> for Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn from(value: Vector3<T>) -> Self {
+        value.0
+    }
+}
+/// Fails, handing back the vertex, if `w` is not `1`
+impl<T: Scalar> TryFrom<Vertex4<T>> for Point3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Error = Vertex4<T>;
+    #[inline]
+    fn try_from(value: Vertex4<T>) -> Result<Self, Self::Error> {
+        if value.is_point() {
+            Ok(Point3(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+/// Fails, handing back the vertex, if `w` is not `0`
+impl<T: Scalar> TryFrom<Vertex4<T>> for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Error = Vertex4<T>;
+    #[inline]
+    fn try_from(value: Vertex4<T>) -> Result<Self, Self::Error> {
+        if value.w() == T::ZERO {
+            Ok(Vector3(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+// Operator impls---------
+//
+// Forwards `$lhs $tr $rhs` to the wrapped vertices for all owned/borrowed
+// combinations, wrapping the result in `$o`
+macro_rules! affine_op_impl {
+    ($lhs:ident, $tr:ident, $f:ident, $rhs:ident, $o:ident) => {
+        impl<T: Scalar> $tr<$rhs<T>> for $lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $o<T>;
+            #[inline]
+            fn $f(self, rhs: $rhs<T>) -> Self::Output {
+                $o($tr::$f(&self.0, &rhs.0))
+            }
+        }
+        impl<T: Scalar> $tr<&$rhs<T>> for $lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $o<T>;
+            #[inline]
+            fn $f(self, rhs: &$rhs<T>) -> Self::Output {
+                $o($tr::$f(&self.0, &rhs.0))
+            }
+        }
+        impl<T: Scalar> $tr<$rhs<T>> for &$lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $o<T>;
+            #[inline]
+            fn $f(self, rhs: $rhs<T>) -> Self::Output {
+                $o($tr::$f(&self.0, &rhs.0))
+            }
+        }
+        impl<T: Scalar> $tr<&$rhs<T>> for &$lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $o<T>;
+            #[inline]
+            fn $f(self, rhs: &$rhs<T>) -> Self::Output {
+                $o($tr::$f(&self.0, &rhs.0))
+            }
+        }
+    };
+}
+macro_rules! affine_op_assign_impl {
+    ($lhs:ident, $tr:ident, $f:ident, $rhs:ident) => {
+        impl<T: Scalar> $tr<$rhs<T>> for $lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            #[inline]
+            fn $f(&mut self, rhs: $rhs<T>) {
+                $tr::$f(&mut self.0, &rhs.0)
+            }
+        }
+        impl<T: Scalar> $tr<&$rhs<T>> for $lhs<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            #[inline]
+            fn $f(&mut self, rhs: &$rhs<T>) {
+                $tr::$f(&mut self.0, &rhs.0)
+            }
+        }
+    };
+}
+affine_op_impl!(Point3, Sub, sub, Point3, Vector3);
+affine_op_impl!(Point3, Add, add, Vector3, Point3);
+affine_op_impl!(Point3, Sub, sub, Vector3, Point3);
+affine_op_impl!(Vector3, Add, add, Point3, Point3);
+affine_op_impl!(Vector3, Add, add, Vector3, Vector3);
+affine_op_impl!(Vector3, Sub, sub, Vector3, Vector3);
+affine_op_assign_impl!(Point3, AddAssign, add_assign, Vector3);
+affine_op_assign_impl!(Point3, SubAssign, sub_assign, Vector3);
+affine_op_assign_impl!(Vector3, AddAssign, add_assign, Vector3);
+affine_op_assign_impl!(Vector3, SubAssign, sub_assign, Vector3);
+impl<T: Scalar> Neg for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vector3(-self.0)
+    }
+}
+impl<T: Scalar> Neg for &Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vector3(-&self.0)
+    }
+}
+impl<T: Scalar> Mul<T> for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector3(self.0 * rhs)
+    }
+}
+impl<T: Scalar> Mul<T> for &Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector3(self.0 * rhs)
+    }
+}
+impl<T: Scalar> MulAssign<T> for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs
+    }
+}
+impl<T: Scalar> Div<T> for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Vector3(self.0 / rhs)
+    }
+}
+impl<T: Scalar> Div<T> for &Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Vector3(self.0 / rhs)
+    }
+}
+impl<T: Scalar> DivAssign<T> for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs
+    }
+}
+// Forwards `$tr` (`Dot` or `Cross`) to the wrapped vertices for all owned/borrowed
+// combinations, `$wrap` turns the vertex result into the output
+macro_rules! vector_product_impl {
+    ($tr:ident, $f:ident, $o:ty, |$v:ident| $wrap:expr) => {
+        vector_product_impl!(@impl $tr, $f, $o, |$v| $wrap, Vector3<T>, Vector3<T>);
+        vector_product_impl!(@impl $tr, $f, $o, |$v| $wrap, Vector3<T>, &Vector3<T>);
+        vector_product_impl!(@impl $tr, $f, $o, |$v| $wrap, &Vector3<T>, Vector3<T>);
+        vector_product_impl!(@impl $tr, $f, $o, |$v| $wrap, &Vector3<T>, &Vector3<T>);
+    };
+    (@impl $tr:ident, $f:ident, $o:ty, |$v:ident| $wrap:expr, $lhs:ty, $rhs:ty) => {
+        impl<T: Scalar> $tr<$rhs> for $lhs
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $o;
+            #[inline]
+            fn $f(self, rhs: $rhs) -> Self::Output {
+                let $v = $tr::$f(&self.0, &rhs.0);
+                $wrap
+            }
+        }
+    };
+}
+vector_product_impl!(Dot, dot, T, |v| v);
+vector_product_impl!(Cross, cross, Vector3<T>, |v| Vector3(v));
+impl<T: Scalar> Mag for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = T;
+    #[inline]
+    fn mag(&self) -> Self::Output {
+        self.0.mag()
+    }
+}
+impl<T: Scalar> Norm for Vector3<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vector3<T>;
+    #[inline]
+    fn norm(&self) -> Self::Output {
+        Vector3(self.0.norm())
+    }
+}
+// Matrix impls---------
+//
+// Only affine transforms keep `w`, so it is not re-checked afterwards
+#[inline]
+fn transform_affine<T: Scalar>(matrix: &Matrix4<T>, vertex: &Vertex4<T>) -> Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    debug_assert!(matrix.is_affine(), "bottom row of an affine transform must be [0, 0, 0, 1]");
+    matrix * vertex
+}
+macro_rules! transform_impl {
+    ($t:ident) => {
+        impl<T: Scalar> Mul<$t<T>> for Matrix4<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $t<T>;
+            #[inline]
+            fn mul(self, rhs: $t<T>) -> Self::Output {
+                $t(transform_affine(&self, &rhs.0))
+            }
+        }
+        impl<T: Scalar> Mul<&$t<T>> for Matrix4<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $t<T>;
+            #[inline]
+            fn mul(self, rhs: &$t<T>) -> Self::Output {
+                $t(transform_affine(&self, &rhs.0))
+            }
+        }
+        impl<T: Scalar> Mul<$t<T>> for &Matrix4<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $t<T>;
+            #[inline]
+            fn mul(self, rhs: $t<T>) -> Self::Output {
+                $t(transform_affine(self, &rhs.0))
+            }
+        }
+        impl<T: Scalar> Mul<&$t<T>> for &Matrix4<T>
+        where
+            Simd<T, 4>: SimdOps<T, 4>,
+        {
+            type Output = $t<T>;
+            #[inline]
+            fn mul(self, rhs: &$t<T>) -> Self::Output {
+                $t(transform_affine(self, &rhs.0))
+            }
+        }
+    };
+}
+transform_impl!(Point3);
+transform_impl!(Vector3);
@@ -0,0 +1,306 @@
+//! Bounding volume hierarchies.
+//!
+//! [`Bvh`] only knows the bounds of the primitives, by index into the slice
+//! it was built from, so it serves triangle meshes and shape groups alike:
+//! the queries take a closure that intersects one primitive.
+//!
+//! The tree is built top-down, each split chosen by the surface area
+//! heuristic over [`BINS`] buckets of the centroids, and stored depth-first
+//! in one array: the left child of an interior node is the next node.
+
+use core::simd::f32x4;
+use core::simd::num::SimdFloat;
+
+use crate::aabb::Aabb;
+use crate::ray::Ray;
+use crate::vertex::Vert4;
+
+/// Buckets per axis the split candidates are taken from
+pub const BINS: usize = 12;
+/// Nodes with at most this many primitives become leaves unless splitting
+/// them is cheaper
+pub const MAX_LEAF_SIZE: usize = 4;
+// Cost of visiting a node, relative to intersecting a primitive
+const TRAVERSAL_COST: f32 = 1.;
+
+#[derive(Debug)]
+pub struct BvhNode {
+    bounds: Aabb,
+    // First primitive for a leaf, the right child for an interior node
+    offset: usize,
+    // Zero for an interior node
+    count: usize,
+}
+impl BvhNode {
+    #[inline]
+    pub fn bounds(&self) -> &Aabb {
+        &self.bounds
+    }
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        self.count > 0
+    }
+    /// Index of the right child of an interior node, the left one is the
+    /// next node
+    #[inline]
+    pub fn right_child(&self) -> Option<usize> {
+        (!self.is_leaf()).then_some(self.offset)
+    }
+    /// Range into [`Bvh::primitives`] of a leaf, empty for interior nodes
+    #[inline]
+    pub fn primitive_range(&self) -> core::ops::Range<usize> {
+        self.offset..self.offset + self.count
+    }
+}
+#[derive(Debug, Default)]
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    primitives: Vec<usize>,
+}
+#[derive(Clone, Copy)]
+struct Bin {
+    count: usize,
+    bounds_min: f32x4,
+    bounds_max: f32x4,
+}
+impl Bin {
+    const EMPTY: Bin = Bin {
+        count: 0,
+        bounds_min: f32x4::from_array([f32::INFINITY; 4]),
+        bounds_max: f32x4::from_array([f32::NEG_INFINITY; 4]),
+    };
+}
+impl Bvh {
+    /// Builds the hierarchy over the primitives with the given bounds
+    pub fn build(bounds: &[Aabb]) -> Bvh {
+        let mut bvh = Bvh { nodes: Vec::with_capacity(2 * bounds.len()), primitives: (0..bounds.len()).collect() };
+        if !bounds.is_empty() {
+            let centroids: Vec<_> = bounds.iter().map(Aabb::centre).collect();
+            bvh.build_node(bounds, &centroids, 0, bounds.len());
+        }
+        bvh
+    }
+    // Appends the node over primitives[start..end] and its subtree
+    fn build_node(&mut self, bounds: &[Aabb], centroids: &[Vert4], start: usize, end: usize) {
+        let node_bounds =
+            self.primitives[start..end].iter().fold(Aabb::empty(), |aabb, &i| aabb.merge(&bounds[i]));
+        let index = self.nodes.len();
+        self.nodes.push(BvhNode { bounds: node_bounds, offset: start, count: end - start });
+        let count = end - start;
+        if count == 1 {
+            return;
+        }
+        let centroid_bounds = Aabb::from_points(self.primitives[start..end].iter().map(|&i| &centroids[i]));
+        let extents = centroid_bounds.extents();
+        // Best (cost, axis, bin) over every axis
+        let mut best: Option<(f32, usize, usize)> = None;
+        for axis in 0..3 {
+            let extent = extents[axis];
+            if extent <= 0. {
+                continue;
+            }
+            let low = centroid_bounds.min()[axis];
+            let mut bins = [Bin::EMPTY; BINS];
+            for &i in &self.primitives[start..end] {
+                let bin = &mut bins[bin_of(centroids[i][axis], low, extent)];
+                bin.count += 1;
+                bin.bounds_min = bin.bounds_min.simd_min(*bounds[i].min().as_simd());
+                bin.bounds_max = bin.bounds_max.simd_max(*bounds[i].max().as_simd());
+            }
+            for split in 1..BINS {
+                let (left, right) = bins.split_at(split);
+                let cost = side_cost(left) + side_cost(right);
+                if best.is_none_or(|(best_cost, _, _)| cost < best_cost) {
+                    best = Some((cost, axis, split));
+                }
+            }
+        }
+        let area = self.nodes[index].bounds.surface_area();
+        let Some((cost, axis, split)) = best else {
+            // All centroids coincide, no split separates them
+            return;
+        };
+        let split_cost = TRAVERSAL_COST * area + cost;
+        let leaf_cost = count as f32 * area;
+        if count <= MAX_LEAF_SIZE && leaf_cost <= split_cost {
+            return;
+        }
+        let low = centroid_bounds.min()[axis];
+        let extent = extents[axis];
+        let in_left = |i: &usize| bin_of(centroids[*i][axis], low, extent) < split;
+        let mid = start + partition(&mut self.primitives[start..end], in_left);
+        if mid == start || mid == end {
+            return;
+        }
+        self.nodes[index].count = 0;
+        self.build_node(bounds, centroids, start, mid);
+        self.nodes[index].offset = self.nodes.len();
+        self.build_node(bounds, centroids, mid, end);
+    }
+    #[inline]
+    pub fn nodes(&self) -> &[BvhNode] {
+        &self.nodes
+    }
+    /// Primitive indices in leaf order
+    #[inline]
+    pub fn primitives(&self) -> &[usize] {
+        &self.primitives
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.primitives.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.primitives.is_empty()
+    }
+    /// The box around everything, `None` without primitives
+    #[inline]
+    pub fn bounds(&self) -> Option<&Aabb> {
+        self.nodes.first().map(BvhNode::bounds)
+    }
+    /// The nearest hit in `[0, t_max)`, as the primitive index and distance.
+    /// `intersect` is given a primitive index and the current nearest
+    /// distance, and returns the distance to the nearest non-negative hit on
+    /// that primitive, if any.
+    pub fn closest_hit<F>(&self, ray: &Ray, t_max: f32, mut intersect: F) -> Option<(usize, f32)>
+    where
+        F: FnMut(usize, f32) -> Option<f32>,
+    {
+        let inv_direction = f32x4::splat(1.) / ray.direction.as_simd();
+        let origin = ray.origin.as_simd();
+        let mut closest: Option<(usize, f32)> = None;
+        let mut t_max = t_max;
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.bounds.slab(origin, &inv_direction, 0., t_max).is_none() {
+                continue;
+            }
+            if node.is_leaf() {
+                for &primitive in &self.primitives[node.primitive_range()] {
+                    if let Some(t) = intersect(primitive, t_max).filter(|&t| t < t_max) {
+                        t_max = t;
+                        closest = Some((primitive, t));
+                    }
+                }
+                continue;
+            }
+            // Push the farther child first so the nearer one is visited first
+            let (left, right) = (index + 1, node.offset);
+            let t_left = self.nodes[left].bounds.slab(origin, &inv_direction, 0., t_max);
+            let t_right = self.nodes[right].bounds.slab(origin, &inv_direction, 0., t_max);
+            match (t_left, t_right) {
+                (Some(l), Some(r)) if l <= r => stack.extend([right, left]),
+                (Some(_), Some(_)) => stack.extend([left, right]),
+                (Some(_), None) => stack.push(left),
+                (None, Some(_)) => stack.push(right),
+                (None, None) => {}
+            }
+        }
+        closest
+    }
+    /// Whether anything is hit closer than `t_max`, stopping at the first
+    /// hit found, as for shadow rays
+    pub fn any_hit<F>(&self, ray: &Ray, t_max: f32, mut intersects: F) -> bool
+    where
+        F: FnMut(usize, f32) -> bool,
+    {
+        let inv_direction = f32x4::splat(1.) / ray.direction.as_simd();
+        let origin = ray.origin.as_simd();
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.bounds.slab(origin, &inv_direction, 0., t_max).is_none() {
+                continue;
+            }
+            if node.is_leaf() {
+                if self.primitives[node.primitive_range()].iter().any(|&primitive| intersects(primitive, t_max)) {
+                    return true;
+                }
+            } else {
+                stack.extend([node.offset, index + 1]);
+            }
+        }
+        false
+    }
+    /// Calls `visit` with every primitive in the leaves whose boxes the ray
+    /// crosses within `[t_min, t_max]`, for queries after every hit rather
+    /// than the nearest
+    pub fn for_each_candidate<F>(&self, ray: &Ray, t_min: f32, t_max: f32, mut visit: F)
+    where
+        F: FnMut(usize),
+    {
+        let inv_direction = f32x4::splat(1.) / ray.direction.as_simd();
+        let origin = ray.origin.as_simd();
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.bounds.slab(origin, &inv_direction, t_min, t_max).is_none() {
+                continue;
+            }
+            if node.is_leaf() {
+                self.primitives[node.primitive_range()].iter().for_each(|&primitive| visit(primitive));
+            } else {
+                stack.extend([node.offset, index + 1]);
+            }
+        }
+    }
+    /// Updates the node bounds after the primitives moved, keeping the tree
+    /// as built. Cheaper than rebuilding, but the tree degrades as the
+    /// primitives drift away from where they were.
+    ///
+    /// # Panics
+    ///
+    /// If `bounds` does not have one box per primitive
+    pub fn refit(&mut self, bounds: &[Aabb]) {
+        assert_eq!(bounds.len(), self.primitives.len(), "refit: one box per primitive expected");
+        // Children come after their parent
+        for index in (0..self.nodes.len()).rev() {
+            let node = &self.nodes[index];
+            let refitted = if node.is_leaf() {
+                self.primitives[node.primitive_range()].iter().fold(Aabb::empty(), |aabb, &i| aabb.merge(&bounds[i]))
+            } else {
+                self.nodes[index + 1].bounds.merge(&self.nodes[node.offset].bounds)
+            };
+            self.nodes[index].bounds = refitted;
+        }
+    }
+}
+// Bin of a centroid coordinate along an axis whose centroids span `extent`
+// from `low`
+#[inline]
+fn bin_of(centroid: f32, low: f32, extent: f32) -> usize {
+    (((centroid - low) / extent * BINS as f32) as usize).min(BINS - 1)
+}
+// Area times primitive count of the box around some bins
+fn side_cost(bins: &[Bin]) -> f32 {
+    let count: usize = bins.iter().map(|bin| bin.count).sum();
+    if count == 0 {
+        return 0.;
+    }
+    let (min, max) = bins.iter().fold((Bin::EMPTY.bounds_min, Bin::EMPTY.bounds_max), |(min, max), bin| {
+        (min.simd_min(bin.bounds_min), max.simd_max(bin.bounds_max))
+    });
+    count as f32 * Aabb::new(&min.into(), &max.into()).surface_area()
+}
+// Moves the elements satisfying `predicate` to the front, returns how many
+fn partition<P: Fn(&usize) -> bool>(slice: &mut [usize], predicate: P) -> usize {
+    let mut first = 0;
+    for i in 0..slice.len() {
+        if predicate(&slice[i]) {
+            slice.swap(first, i);
+            first += 1;
+        }
+    }
+    first
+}
@@ -0,0 +1,102 @@
+//! Pinhole camera.
+//!
+//! The camera looks down -z from the origin of its own space at a canvas one
+//! unit away, and the view transform takes world space there. Pixel `(0, 0)`
+//! is the top left corner.
+
+use crate::canvas::Canvas;
+use crate::color::Color;
+use crate::ray::Ray;
+use crate::transform::Transform;
+use crate::vertex::{Norm, Vert4};
+
+#[derive(Debug)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: f32,
+    transform: Transform,
+    half_width: f32,
+    half_height: f32,
+    pixel_size: f32,
+}
+impl Camera {
+    /// `hsize` by `vsize` pixels, `field_of_view` in radians across the
+    /// longer side, looking down -z from the origin
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f32) -> Camera {
+        let half_view = (field_of_view / 2.).tan();
+        let aspect = hsize as f32 / vsize as f32;
+        let (half_width, half_height) =
+            if aspect >= 1. { (half_view, half_view / aspect) } else { (half_view * aspect, half_view) };
+        Camera {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Transform::identity(),
+            half_width,
+            half_height,
+            pixel_size: half_width * 2. / hsize as f32,
+        }
+    }
+    #[inline]
+    pub fn with_transform(mut self, transform: Transform) -> Camera {
+        self.transform = transform;
+        self
+    }
+    #[inline]
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform
+    }
+    /// World to camera space
+    #[inline]
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+    #[inline]
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+    #[inline]
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+    #[inline]
+    pub fn field_of_view(&self) -> f32 {
+        self.field_of_view
+    }
+    /// Half the width of the canvas, one unit in front of the camera
+    #[inline]
+    pub fn half_width(&self) -> f32 {
+        self.half_width
+    }
+    #[inline]
+    pub fn half_height(&self) -> f32 {
+        self.half_height
+    }
+    /// Size of a pixel on the canvas, one unit in front of the camera
+    #[inline]
+    pub fn pixel_size(&self) -> f32 {
+        self.pixel_size
+    }
+    /// The world space ray from the camera through the centre of pixel
+    /// `(px, py)`
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        let world_x = self.half_width - (px as f32 + 0.5) * self.pixel_size;
+        let world_y = self.half_height - (py as f32 + 0.5) * self.pixel_size;
+        let inverse = self.transform.inverse();
+        let pixel = inverse * Vert4::point(world_x, world_y, -1.);
+        let origin = inverse * Vert4::point(0., 0., 0.);
+        let direction = (pixel - origin).norm();
+        Ray::new(origin, direction)
+    }
+    /// Colours every pixel with `color_at` of its ray
+    pub fn render<F: FnMut(&Ray) -> Color>(&self, mut color_at: F) -> Canvas {
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        for y in 0..self.vsize {
+            for x in 0..self.hsize {
+                image.write_pixel(x, y, color_at(&self.ray_for_pixel(x, y)));
+            }
+        }
+        image
+    }
+}
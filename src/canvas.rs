@@ -0,0 +1,164 @@
+//! Grids of colour pixels, and their PPM encoding.
+
+use std::fmt::Write;
+
+use crate::color::Color;
+
+/// Longest line of a plain PPM file
+const PPM_LINE_WIDTH: usize = 70;
+
+#[derive(Debug, PartialEq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+/// Why [`Canvas::from_ppm`] refused its input
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PpmError {
+    /// Neither `P3` nor `P6`
+    UnsupportedFormat,
+    /// The header or the raster stops short
+    UnexpectedEnd,
+    /// A header field or plain sample is not a number
+    InvalidNumber,
+    /// A sample above the maximum value, a maximum value outside `1..=65535`,
+    /// or a width times height that overflows
+    OutOfRange,
+}
+impl Canvas {
+    /// All black
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas { width, height, pixels: (0..width * height).map(|_| Color::BLACK).collect() }
+    }
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    #[inline]
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "pixel ({x}, {y}) outside a {}x{} canvas", self.width, self.height);
+        y * self.width + x
+    }
+    /// # Panics
+    ///
+    /// If `(x, y)` is outside the canvas
+    #[inline]
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Color) {
+        let index = self.index(x, y);
+        self.pixels[index] = color;
+    }
+    /// # Panics
+    ///
+    /// If `(x, y)` is outside the canvas
+    #[inline]
+    pub fn pixel_at(&self, x: usize, y: usize) -> &Color {
+        &self.pixels[self.index(x, y)]
+    }
+    /// Rows from the top, pixels from the left
+    #[inline]
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+    /// Plain PPM (`P3`), samples clamped and scaled to `0..=255`, lines
+    /// wrapped before 70 columns and every row on lines of its own
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = format!("P3\n{} {}\n255\n", self.width, self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let mut line_len = 0;
+            for sample in row.iter().flat_map(Color::to_rgb8) {
+                let sample_len = if sample >= 100 { 3 } else if sample >= 10 { 2 } else { 1 };
+                if line_len > 0 && line_len + 1 + sample_len > PPM_LINE_WIDTH {
+                    ppm.push('\n');
+                    line_len = 0;
+                }
+                if line_len > 0 {
+                    ppm.push(' ');
+                    line_len += 1;
+                }
+                write!(ppm, "{sample}").expect("writing to a String cannot fail");
+                line_len += sample_len;
+            }
+            ppm.push('\n');
+        }
+        ppm
+    }
+    /// Reads a plain (`P3`) or binary (`P6`) PPM file, samples scaled from
+    /// the maximum value to `0..=1`
+    pub fn from_ppm(ppm: &[u8]) -> Result<Canvas, PpmError> {
+        let mut reader = PpmReader { ppm, position: 0 };
+        let binary = match reader.token()? {
+            b"P3" => false,
+            b"P6" => true,
+            _ => return Err(PpmError::UnsupportedFormat),
+        };
+        let width = reader.number()?;
+        let height = reader.number()?;
+        let len = width.checked_mul(height).ok_or(PpmError::OutOfRange)?;
+        let max = reader.number()?;
+        if !(1..=65535).contains(&max) {
+            return Err(PpmError::OutOfRange);
+        }
+        if binary {
+            // A single whitespace byte separates the header from the raster
+            reader.position += 1;
+        }
+        let mut sample = || -> Result<f32, PpmError> {
+            let value = if binary { reader.binary_sample(max > 255)? } else { reader.number()? };
+            if value > max {
+                return Err(PpmError::OutOfRange);
+            }
+            Ok(value as f32 / max as f32)
+        };
+        let pixels = (0..len)
+            .map(|_| Ok(Color::new(sample()?, sample()?, sample()?)))
+            .collect::<Result<Vec<_>, PpmError>>()?;
+        Ok(Canvas { width, height, pixels })
+    }
+}
+struct PpmReader<'a> {
+    ppm: &'a [u8],
+    position: usize,
+}
+impl<'a> PpmReader<'a> {
+    // Skips whitespace and comments, up to the end of their line
+    fn skip_blanks(&mut self) {
+        while let Some(&byte) = self.ppm.get(self.position) {
+            if byte == b'#' {
+                while self.ppm.get(self.position).is_some_and(|&byte| byte != b'\n') {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+    fn token(&mut self) -> Result<&'a [u8], PpmError> {
+        self.skip_blanks();
+        let start = self.position;
+        while self.ppm.get(self.position).is_some_and(|byte| !byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(PpmError::UnexpectedEnd);
+        }
+        Ok(&self.ppm[start..self.position])
+    }
+    fn number(&mut self) -> Result<usize, PpmError> {
+        let token = self.token()?;
+        core::str::from_utf8(token).ok().and_then(|token| token.parse().ok()).ok_or(PpmError::InvalidNumber)
+    }
+    // One byte per sample, two big-endian ones above 255
+    fn binary_sample(&mut self, wide: bool) -> Result<usize, PpmError> {
+        let len = if wide { 2 } else { 1 };
+        let bytes = self.ppm.get(self.position..self.position + len).ok_or(PpmError::UnexpectedEnd)?;
+        self.position += len;
+        Ok(bytes.iter().fold(0, |value, &byte| value << 8 | byte as usize))
+    }
+}
@@ -0,0 +1,141 @@
+//! Linear RGBA colours.
+//!
+//! Red, green and blue run from `0` to `1` over the displayable range but may
+//! go beyond it while shading, alpha is `1` for opaque. Arithmetic works on
+//! the colour channels: sums, differences and scaling keep the alpha of the
+//! left operand, the Hadamard product multiplies it too.
+
+use core::ops::{Add, AddAssign};
+use core::ops::{Div, DivAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Sub, SubAssign};
+use core::simd::Simd;
+use core::simd::num::{SimdFloat, SimdUint};
+
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::simd_almost_eq;
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct Color4<T: Scalar>(Simd<T, 4>);
+pub type Color = Color4<f32>;
+pub type DColor = Color4<f64>;
+
+impl<T: Scalar> Color4<T> {
+    pub const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const WHITE: Self = Self::new(T::ONE, T::ONE, T::ONE);
+    /// Opaque
+    #[inline]
+    pub const fn new(r: T, g: T, b: T) -> Self {
+        Self::rgba(r, g, b, T::ONE)
+    }
+    #[inline]
+    pub const fn rgba(r: T, g: T, b: T, a: T) -> Self {
+        Self(Simd::from_array([r, g, b, a]))
+    }
+    #[inline]
+    pub const fn r(&self) -> T {
+        self.0.as_array()[0]
+    }
+    #[inline]
+    pub const fn g(&self) -> T {
+        self.0.as_array()[1]
+    }
+    #[inline]
+    pub const fn b(&self) -> T {
+        self.0.as_array()[2]
+    }
+    #[inline]
+    pub const fn a(&self) -> T {
+        self.0.as_array()[3]
+    }
+    #[inline]
+    pub const fn as_simd(&self) -> &Simd<T, 4> {
+        &self.0
+    }
+}
+impl<T: Scalar> Color4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    /// Every channel clamped to `[0, 1]`
+    #[inline]
+    pub fn clamp(&self) -> Color4<T> {
+        Color4(self.0.simd_clamp(Simd::splat(T::ZERO), Simd::splat(T::ONE)))
+    }
+}
+impl Color4<f32> {
+    /// Channels clamped and scaled to `0..=255`, rounded
+    #[inline]
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        // Non-negative once clamped, so truncating after adding a half rounds
+        let scaled = self.clamp().0 * Simd::splat(255.) + Simd::splat(0.5);
+        scaled.cast::<u8>().to_array()
+    }
+    #[inline]
+    pub fn to_rgb8(&self) -> [u8; 3] {
+        let [r, g, b, _] = self.to_rgba8();
+        [r, g, b]
+    }
+    #[inline]
+    pub fn from_rgba8([r, g, b, a]: [u8; 4]) -> Color {
+        Color4(Simd::from_array([r, g, b, a]).cast::<f32>() / Simd::splat(255.))
+    }
+    /// Encodes the colour channels with the sRGB transfer function, alpha
+    /// stays linear
+    #[inline]
+    pub fn to_srgb(&self) -> Color {
+        Color::rgba(srgb_encode(self.r()), srgb_encode(self.g()), srgb_encode(self.b()), self.a())
+    }
+    /// Decodes sRGB colour channels back to linear, alpha stays as is
+    #[inline]
+    pub fn to_linear(&self) -> Color {
+        Color::rgba(srgb_decode(self.r()), srgb_decode(self.g()), srgb_decode(self.b()), self.a())
+    }
+}
+// IEC 61966-2-1: linear near black, a 2.4 power curve above
+#[inline]
+fn srgb_encode(linear: f32) -> f32 {
+    if linear <= 0.003_130_8 { 12.92 * linear } else { 1.055 * linear.powf(1. / 2.4) - 0.055 }
+}
+#[inline]
+fn srgb_decode(encoded: f32) -> f32 {
+    if encoded <= 0.040_45 { encoded / 12.92 } else { ((encoded + 0.055) / 1.055).powf(2.4) }
+}
+impl<T: Scalar> Default for Color4<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::BLACK
+    }
+}
+impl<T: Scalar> From<Simd<T, 4>> for Color4<T> {
+    #[inline]
+    fn from(value: Simd<T, 4>) -> Self {
+        Self(value)
+    }
+}
+impl<T: Scalar> PartialEq for Color4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        simd_almost_eq(&self.0, &other.0)
+    }
+}
+// `rgb` with the alpha of `alpha`
+#[inline]
+fn with_alpha<T: Scalar>(mut rgb: Simd<T, 4>, alpha: Simd<T, 4>) -> Simd<T, 4>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    rgb[3] = alpha[3];
+    rgb
+}
+// Operator impls---------
+binop_impl!(Color4 [Simd<T, 4>: SimdOps<T, 4>], Add, add, AddAssign, add_assign, |l, r| with_alpha(l + r, l));
+binop_impl!(Color4 [Simd<T, 4>: SimdOps<T, 4>], Sub, sub, SubAssign, sub_assign, |l, r| with_alpha(l - r, l));
+// Hadamard product
+binop_impl!(Color4 [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| l * r);
+scalar_op_impl!(Color4 [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| with_alpha(l * Simd::splat(r), l));
+scalar_op_impl!(Color4 [Simd<T, 4>: SimdOps<T, 4>], Div, div, DivAssign, div_assign, |l, r| with_alpha(l / Simd::splat(r), l));
@@ -1,13 +1,49 @@
 #![feature(portable_simd)]
+pub mod aabb;
+pub use aabb::Aabb;
+pub mod affine;
+pub use affine::{DPoint, DVector, Point, Point3, Vector, Vector3};
+pub mod bvh;
+pub use bvh::Bvh;
+pub mod camera;
+pub use camera::Camera;
+pub mod canvas;
+pub use canvas::Canvas;
 pub mod matrix;
 pub use matrix::{Cofactor, Determinant, Matr2, Matr3, Matr4, Matrix, Minor, Submatrix};
+pub use matrix::{DMatr2, DMatr3, DMatr4, Matrix2, Matrix3, Matrix4};
 #[macro_use]
 pub mod macros;
+// After `macros`, whose operator impl macros it uses
+pub mod color;
+pub use color::{Color, Color4, DColor};
+pub mod lighting;
+pub use lighting::{Material, PointLight, lighting, lighting_object};
+pub mod pattern;
+pub use pattern::Pattern;
+pub mod quaternion;
+pub use quaternion::{DQuat, Quat, Quaternion};
+pub mod ray;
+pub use ray::Ray;
+pub mod scalar;
+pub use scalar::Scalar;
+pub mod shapes;
+pub use shapes::{Intersection, Intersections, Shape};
+pub mod transform;
+pub use transform::Transform;
+pub mod uv;
+pub use uv::{TextureMap, UvMapping, UvPattern};
 pub mod vertex;
 pub use vertex::{Cross, Dot, Mag, Norm, Vert2, Vert3, Vert4};
+pub use vertex::{DVert2, DVert3, DVert4, Vertex2, Vertex3, Vertex4};
+pub mod world;
+pub use world::World;
 
 #[macro_export]
 macro_rules! vertex {
+    ($t:ty; $x:expr, $y:expr, $z:expr, $w:expr) => {
+        $crate::vertex::Vertex4::<$t>::new($x as $t, $y as $t, $z as $t, $w as $t)
+    };
     ($x:literal, $y:literal, $z:literal, $w:literal) => {
         $crate::vertex::Vert4::new($x as f32, $y as f32, $z as f32, $w as f32)
     };
@@ -17,11 +53,13 @@ macro_rules! vertex {
 }
 #[macro_export]
 macro_rules! point {
+    ($t:ty; $x:expr, $y:expr, $z:expr) => {{ $crate::vertex::Vertex4::<$t>::point($x as $t, $y as $t, $z as $t) }};
     ($x:literal, $y:literal, $z:literal) => {{ $crate::vertex::Vert4::point($x as f32, $y as f32, $z as f32) }};
     ($x:expr, $y:expr, $z:expr) => {{ $crate::vertex::Vert4::point($x as f32, $y as f32, $z as f32) }};
 }
 #[macro_export]
 macro_rules! vector {
+    ($t:ty; $x:expr, $y:expr, $z:expr) => {{ $crate::vertex::Vertex4::<$t>::vector($x as $t, $y as $t, $z as $t) }};
     ($x:literal, $y:literal, $z:literal) => {{ $crate::vertex::Vert4::vector($x as f32, $y as f32, $z as f32) }};
     ($x:expr, $y:expr, $z:expr) => {{ $crate::vertex::Vert4::vector($x as f32, $y as f32, $z as f32) }};
 }
@@ -48,6 +86,9 @@ macro_rules! vuz {
 }
 #[macro_export]
 macro_rules! mat2 {
+    ($t:ty; $($arr:expr),*$(,)?) => {{
+        <$crate::matrix::Matrix2<$t> as $crate::matrix::FromArray<$t, 4>>::from_array([$($arr as $t),*])
+    }};
     ($arr:literal) => {{
         <$crate::matrix::Matr2 as $crate::matrix::FromArray<f32, 4>>::from_array($arr)
     }};
@@ -61,6 +102,9 @@ macro_rules! mat2 {
 #[macro_export]
 macro_rules! mat3 {
 
+    ($t:ty; $($arr:expr),*$(,)?) => {{
+        <$crate::matrix::Matrix3<$t> as $crate::matrix::FromArray<$t, 9>>::from_array([$($arr as $t),*])
+    }};
     ($arr:literal) => {{
         <$crate::matrix::Matr3 as $crate::matrix::FromArray<f32, 9>>::from_array($arr)
     }};
@@ -73,6 +117,9 @@ macro_rules! mat3 {
 }
 #[macro_export]
 macro_rules! mat4 {
+    ($t:ty; $($arr:expr),*$(,)?) => {{
+        <$crate::matrix::Matrix4<$t> as $crate::matrix::FromArray<$t, 16>>::from_array([$($arr as $t),*])
+    }};
     ($arr:literal) => {{
         <$crate::matrix::Matr4 as $crate::matrix::FromArray<f32, 16>>::from_array($arr)
     }};
@@ -84,21 +131,8 @@ macro_rules! mat4 {
     }};
 }
 
-pub enum TupleType {
-    Point,
-    Vector,
-}
-
-pub trait PointType {
-    fn new(x: f32, y: f32, z: f32) -> Self;
-    fn is_vector(&self) -> bool;
-    #[inline]
-    fn is_point(&self) -> bool {
-        !self.is_vector()
-    }
-}
 /// # Pure
 pub fn clock(twelve: Vert4) -> impl Iterator<Item = Vert4> {
     use core::f32::consts::PI;
-    (0..12).map(move |idx| Matr4::rotation_y_rad((idx as f32 * (2. * PI)) / 12.0) * &twelve)
+    (0..12).map(move |idx| Matr4::rotation_y_rad((idx as f32 * (2. * PI)) / 12.0) * twelve)
 }
@@ -0,0 +1,107 @@
+//! Phong shading.
+
+use crate::color::Color;
+use crate::pattern::Pattern;
+use crate::shapes::Shape;
+use crate::vertex::{Dot, Norm, Vert4};
+
+/// Surface properties for [`lighting`]. `reflective`, `transparency` and
+/// `refractive_index` are for tracing secondary rays, shading a single point
+/// does not look at them.
+#[derive(Debug)]
+pub struct Material {
+    /// Of the whole surface, unless there is a `pattern`
+    pub color: Color,
+    /// Paints over `color`, see [`lighting_object`]
+    pub pattern: Option<Box<dyn Pattern>>,
+    /// Share of the light's colour reaching everywhere, `0` to `1`
+    pub ambient: f32,
+    /// Share of the light scattered evenly, `0` to `1`
+    pub diffuse: f32,
+    /// Strength of the highlight, `0` to `1`
+    pub specular: f32,
+    /// Larger values give smaller, sharper highlights, typically `10` to `200`
+    pub shininess: f32,
+    /// `0` for matte to `1` for a mirror
+    pub reflective: f32,
+    /// `0` for opaque to `1` for fully transparent
+    pub transparency: f32,
+    /// `1` for vacuum, `1.5` for glass
+    pub refractive_index: f32,
+}
+impl Default for Material {
+    #[inline]
+    fn default() -> Self {
+        Material {
+            color: Color::WHITE,
+            pattern: None,
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.,
+            reflective: 0.,
+            transparency: 0.,
+            refractive_index: 1.,
+        }
+    }
+}
+impl Material {
+    /// The surface colour at a world space point on `object`, from the
+    /// pattern if there is one
+    #[inline]
+    pub fn color_at(&self, object: &dyn Shape, point: &Vert4) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.color_at_object(object, point),
+            None => self.color,
+        }
+    }
+}
+/// Light radiating from a point, with no size
+#[derive(Debug, PartialEq)]
+pub struct PointLight {
+    pub position: Vert4,
+    pub intensity: Color,
+}
+impl PointLight {
+    #[inline]
+    pub const fn new(position: Vert4, intensity: Color) -> PointLight {
+        PointLight { position, intensity }
+    }
+}
+/// The colour of `point` as seen from the eye along `eyev`, both it and
+/// `normalv` normalized vectors. In shadow only the ambient term remains.
+///
+/// Shades in `material.color`, ignoring any pattern, which needs the object
+/// it is on: see [`lighting_object`].
+#[inline]
+pub fn lighting(material: &Material, light: &PointLight, point: &Vert4, eyev: &Vert4, normalv: &Vert4, in_shadow: bool) -> Color {
+    shade(material, &material.color, light, point, eyev, normalv, in_shadow)
+}
+/// [`lighting`] with the material of `object`, in the colour its pattern has
+/// at `point`
+#[inline]
+pub fn lighting_object(object: &dyn Shape, light: &PointLight, point: &Vert4, eyev: &Vert4, normalv: &Vert4, in_shadow: bool) -> Color {
+    let material = object.material();
+    shade(material, &material.color_at(object, point), light, point, eyev, normalv, in_shadow)
+}
+fn shade(material: &Material, color: &Color, light: &PointLight, point: &Vert4, eyev: &Vert4, normalv: &Vert4, in_shadow: bool) -> Color {
+    let effective_color = color * light.intensity;
+    let ambient = effective_color * material.ambient;
+    if in_shadow {
+        return ambient;
+    }
+    let lightv = (light.position - point).norm();
+    // Negative when the light is on the other side of the surface
+    let light_dot_normal = Dot::dot(&lightv, normalv);
+    if light_dot_normal < 0. {
+        return ambient;
+    }
+    let diffuse = effective_color * (material.diffuse * light_dot_normal);
+    // Negative when the reflection points away from the eye
+    let reflect_dot_eye = Dot::dot(&(-lightv).reflect(normalv), eyev);
+    if reflect_dot_eye <= 0. {
+        return ambient + diffuse;
+    }
+    let specular = light.intensity * (material.specular * reflect_dot_eye.powf(material.shininess));
+    ambient + diffuse + specular
+}
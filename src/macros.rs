@@ -39,3 +39,179 @@ macro_rules! vector_3 {
         vector!($x, $x, $x)
     };
 }
+// Operator impls for newtypes over a `Simd` or array of `T: Scalar`
+//
+// `$l` and `$r` name the wrapped storage of the left and right operand, `$e`
+// computes the wrapped storage of the output from them. The bracketed tokens
+// are the where-clause of every generated impl.
+macro_rules! binop_impl {
+    ($t:ident [$($w:tt)*], $tr:ident, $f:ident, $tra:ident, $fa:ident, |$l:ident, $r:ident| $e:expr) => {
+        impl<T: $crate::scalar::Scalar> $tr for $t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, rhs: $t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&$t<T>> for $t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, rhs: &$t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<$t<T>> for &$t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, rhs: $t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&$t<T>> for &$t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, rhs: &$t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tra for $t<T> where $($w)* {
+            #[inline]
+            fn $fa(&mut self, rhs: $t<T>) {
+                let ($l, $r) = (self.0, rhs.0);
+                self.0 = $e
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tra<&$t<T>> for $t<T> where $($w)* {
+            #[inline]
+            fn $fa(&mut self, rhs: &$t<T>) {
+                let ($l, $r) = (self.0, rhs.0);
+                self.0 = $e
+            }
+        }
+    };
+}
+// Same as `binop_impl`, but `$r` is the scalar right hand side
+macro_rules! scalar_op_impl {
+    ($t:ident [$($w:tt)*], $tr:ident, $f:ident, $tra:ident, $fa:ident, |$l:ident, $r:ident| $e:expr) => {
+        impl<T: $crate::scalar::Scalar> $tr<T> for $t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, $r: T) -> Self::Output {
+                let $l = self.0;
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&T> for $t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, &$r: &T) -> Self::Output {
+                let $l = self.0;
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<T> for &$t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, $r: T) -> Self::Output {
+                let $l = self.0;
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&T> for &$t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn $f(self, &$r: &T) -> Self::Output {
+                let $l = self.0;
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tra<T> for $t<T> where $($w)* {
+            #[inline]
+            fn $fa(&mut self, $r: T) {
+                let $l = self.0;
+                self.0 = $e
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tra<&T> for $t<T> where $($w)* {
+            #[inline]
+            fn $fa(&mut self, &$r: &T) {
+                let $l = self.0;
+                self.0 = $e
+            }
+        }
+    };
+}
+// Implements `$tr` (`Dot` or `Cross`) for all owned/borrowed combinations
+macro_rules! product_impl {
+    ($t:ident [$($w:tt)*], $tr:ident, $f:ident, $o:ty, |$l:ident, $r:ident| $e:expr) => {
+        impl<T: $crate::scalar::Scalar> $tr<$t<T>> for $t<T> where $($w)* {
+            type Output = $o;
+            #[inline]
+            fn $f(self, rhs: $t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $e
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&$t<T>> for $t<T> where $($w)* {
+            type Output = $o;
+            #[inline]
+            fn $f(self, rhs: &$t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $e
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<$t<T>> for &$t<T> where $($w)* {
+            type Output = $o;
+            #[inline]
+            fn $f(self, rhs: $t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $e
+            }
+        }
+        impl<T: $crate::scalar::Scalar> $tr<&$t<T>> for &$t<T> where $($w)* {
+            type Output = $o;
+            #[inline]
+            fn $f(self, rhs: &$t<T>) -> Self::Output {
+                let ($l, $r) = (self.0, rhs.0);
+                $e
+            }
+        }
+    };
+}
+macro_rules! neg_impl {
+    ($t:ident [$($w:tt)*], |$v:ident| $e:expr) => {
+        impl<T: $crate::scalar::Scalar> core::ops::Neg for $t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                let $v = self.0;
+                $t($e)
+            }
+        }
+        impl<T: $crate::scalar::Scalar> core::ops::Neg for &$t<T> where $($w)* {
+            type Output = $t<T>;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                let $v = self.0;
+                $t($e)
+            }
+        }
+    };
+}
+// The transform accessors of a `Pattern` keeping it in the field `transform`
+macro_rules! pattern_transform_impl {
+    () => {
+        #[inline]
+        fn transform(&self) -> &Transform {
+            &self.transform
+        }
+        #[inline]
+        fn set_transform(&mut self, transform: Transform) {
+            self.transform = transform
+        }
+    };
+}
@@ -1,11 +1,16 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
 use core::marker::PhantomData;
 use core::ops::{Index, IndexMut};
 use core::ops::Mul;
 
-use std::simd::{f32x16, f32x4, simd_swizzle, LaneCount, Simd, SimdElement, SupportedLaneCount};
+use std::simd::{simd_swizzle, LaneCount, Simd, SimdElement, SupportedLaneCount};
 
-use crate::{matrix::ops::ConstIndex, Vert2, Vert3, Vert4};
+use crate::matrix::ops::ConstIndex;
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::{Cross, Mag, Norm, Vertex2, Vertex3, Vertex4};
 
+mod decompose;
+pub use decompose::Decomposition;
 mod ops;
 pub use ops::{Cofactor, Determinant, Minor, Submatrix, Inverse};
 
@@ -54,36 +59,38 @@ pub trait FromSlice<T> {
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Matr2(pub(crate) f32x4);
-impl FromArray<f32, 4> for Matr2 {
+pub struct Matrix2<T: Scalar>(pub(crate) Simd<T, 4>);
+pub type Matr2 = Matrix2<f32>;
+pub type DMatr2 = Matrix2<f64>;
+impl<T: Scalar> FromArray<T, 4> for Matrix2<T> {
     #[inline]
-    fn from_array(array: [f32; 4]) -> Self {
-        Matr2(f32x4::from_array(array))
+    fn from_array(array: [T; 4]) -> Self {
+        Matrix2(Simd::from_array(array))
     }
 }
-impl AsArray<f32, 4> for Matr2 {
+impl<T: Scalar> AsArray<T, 4> for Matrix2<T> {
     #[inline]
-    fn as_array(&self) -> &[f32; 4] {
+    fn as_array(&self) -> &[T; 4] {
         self.0.as_array()
     }
 }
-impl FromSlice<f32> for Matr2 {
+impl<T: Scalar> FromSlice<T> for Matrix2<T> {
     #[inline]
-    fn from_slice(slice: &[f32]) -> Self {
-        Matr2(f32x4::from_slice(slice))
+    fn from_slice(slice: &[T]) -> Self {
+        Matrix2(Simd::from_slice(slice))
     }
 }
 pub(crate) const T_SWIZZLE_2: [usize; 4] = [0, 2, 1, 3];
-impl Matrix<2> for Matr2 {
-    type Vert = Vert2;
+impl<T: Scalar> Matrix<2> for Matrix2<T> {
+    type Vert = Vertex2<T>;
     
     #[inline]
     fn identity() -> Self {
-        Matr2::from_array([1.0, 0.0, 0.0, 1.0])
+        Matrix2::from_array([T::ONE, T::ZERO, T::ZERO, T::ONE])
     }
     #[inline]
     fn transpose(&self) -> Self {
-        Matr2(simd_swizzle!(self.0, T_SWIZZLE_2))
+        Matrix2(simd_swizzle!(self.0, T_SWIZZLE_2))
     }
     
     // #[inline]
@@ -95,69 +102,71 @@ impl Matrix<2> for Matr2 {
     //     self.into()
     // }
 }
-impl Index<(usize, usize)> for Matr2 {
-    type Output = f32;
+impl<T: Scalar> Index<(usize, usize)> for Matrix2<T> {
+    type Output = T;
     #[inline]
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         &self.0[index.1 + (2 * index.0)]
     }
 }
-impl ConstIndex<usize> for Matr2 {
-    type Output = f32;
+impl<T: Scalar> ConstIndex<usize> for Matrix2<T> {
+    type Output = T;
     #[inline]
     fn const_index(&self, index: usize) -> &Self::Output {
         &self.0.as_array()[index]
     }
 }
-impl ConstIndex<(usize, usize)> for Matr2 {
-    type Output = f32;
+impl<T: Scalar> ConstIndex<(usize, usize)> for Matrix2<T> {
+    type Output = T;
     #[inline]
     fn const_index(&self, index: (usize, usize)) -> &Self::Output {
         self.const_index(index.1 + (2 * index.0))
     }
 }
-impl From<Matr2> for [Vert2; 2] {
+impl<T: Scalar> From<Matrix2<T>> for [Vertex2<T>; 2] {
     #[inline]
-    fn from(value: Matr2) -> Self {
-        let v0 = Vert2::new(value[(0, 0)], value[(0, 1)]);
-        let v1 = Vert2::new(value[(1, 0)], value[(1, 1)]);
+    fn from(value: Matrix2<T>) -> Self {
+        let v0 = Vertex2::new(value[(0, 0)], value[(0, 1)]);
+        let v1 = Vertex2::new(value[(1, 0)], value[(1, 1)]);
         [v0, v1]
     }
 }
-impl From<&Matr2> for [Vert2; 2] {
+impl<T: Scalar> From<&Matrix2<T>> for [Vertex2<T>; 2] {
     #[inline]
-    fn from(value: &Matr2) -> Self {
-        let v0 = Vert2::new(value[(0, 0)], value[(0, 1)]);
-        let v1 = Vert2::new(value[(1, 0)], value[(1, 1)]);
+    fn from(value: &Matrix2<T>) -> Self {
+        let v0 = Vertex2::new(value[(0, 0)], value[(0, 1)]);
+        let v1 = Vertex2::new(value[(1, 0)], value[(1, 1)]);
         [v0, v1]
     }
 }
 #[derive(Debug, PartialEq)]
-pub struct Matr3([f32; 9]);
-impl FromArray<f32, 9> for Matr3 {
+pub struct Matrix3<T: Scalar>([T; 9]);
+pub type Matr3 = Matrix3<f32>;
+pub type DMatr3 = Matrix3<f64>;
+impl<T: Scalar> FromArray<T, 9> for Matrix3<T> {
 
     #[inline]
-    fn from_array(array: [f32; 9]) -> Matr3 {
-        Matr3(array)
+    fn from_array(array: [T; 9]) -> Matrix3<T> {
+        Matrix3(array)
     }
 }
-impl FromSlice<f32> for Matr3 {
+impl<T: Scalar> FromSlice<T> for Matrix3<T> {
     #[inline]
-    fn from_slice(slice: &[f32]) -> Matr3 {
+    fn from_slice(slice: &[T]) -> Matrix3<T> {
         assert!(
             slice.len() >= 9,
             "slice length must be at least the number of elements"
         );
         // SAFETY: We just checked that the slice contains
         // at least `N` elements.
-        unsafe { Matr3::load(slice.as_ptr().cast()) }
+        unsafe { Matrix3::load(slice.as_ptr().cast()) }
     }
 }
-impl Matr3 {
+impl<T: Scalar> Matrix3<T> {
     #[inline]
-    const unsafe fn load(ptr: *const [f32; 9]) -> Matr3 {
-        let mut tmp = core::mem::MaybeUninit::<Matr3>::uninit();
-        // SAFETY: `Mat3` always contains `9` elements of type `f32`.  It may have padding
+    const unsafe fn load(ptr: *const [T; 9]) -> Matrix3<T> {
+        let mut tmp = core::mem::MaybeUninit::<Matrix3<T>>::uninit();
+        // SAFETY: `Mat3` always contains `9` elements of type `T`.  It may have padding
         // which does not need to be initialized.  The safety of reading `ptr` is ensured by the
         // caller.
         unsafe {
@@ -166,16 +175,17 @@ impl Matr3 {
         }
     }
 }
-impl  AsArray<f32, 9> for Matr3 {
-    fn as_array(&self) -> &[f32; 9] {
+impl<T: Scalar> AsArray<T, 9> for Matrix3<T> {
+    fn as_array(&self) -> &[T; 9] {
         &self.0
     }
 }
-impl  Matrix<3> for Matr3 {
-    type Vert = Vert3;
+impl<T: Scalar> Matrix<3> for Matrix3<T> {
+    type Vert = Vertex3<T>;
     #[inline]
     fn identity() -> Self {
-        Self::from_array([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])
+        let (o, l) = (T::ZERO, T::ONE);
+        Self::from_array([l, o, o, o, l, o, o, o, l])
     }
     #[inline]
     fn transpose(&self) -> Self {
@@ -196,24 +206,24 @@ impl  Matrix<3> for Matr3 {
     //     self.into()
     // }
 }
-impl ConstIndex<(usize, usize)> for Matr3 {
-    type Output = f32;
+impl<T: Scalar> ConstIndex<(usize, usize)> for Matrix3<T> {
+    type Output = T;
     #[inline]
     fn const_index(&self, index: (usize, usize)) -> &Self::Output {
         &self.0[index.1 + (3 * index.0)]
     }
 }
-impl Index<(usize, usize)> for Matr3 {
-    type Output = f32;
+impl<T: Scalar> Index<(usize, usize)> for Matrix3<T> {
+    type Output = T;
     #[inline]
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         self.const_index(index)
     }
 }
-impl  FromArray<Vert3, 3> for Matr3 {
+impl<T: Scalar> FromArray<Vertex3<T>, 3> for Matrix3<T> {
     #[inline]
-    fn from_array(arr: [Vert3; 3]) -> Self {
-        let mut out_arr = [0.; 9];
+    fn from_array(arr: [Vertex3<T>; 3]) -> Self {
+        let mut out_arr = [T::ZERO; 9];
         let mut idx = 0;
         while idx <9 {
             let src_idx = match idx {
@@ -225,24 +235,24 @@ impl  FromArray<Vert3, 3> for Matr3 {
             out_arr[idx] = arr[src_idx][idx % 3];
             idx += 1;
         }
-        Matr3::from_array(out_arr)
+        Matrix3::from_array(out_arr)
     }
 }
-impl From<Matr3> for [Vert3; 3] {
+impl<T: Scalar> From<Matrix3<T>> for [Vertex3<T>; 3] {
     #[inline]
-    fn from(value: Matr3) -> Self {
-        let v0 = Vert3::new(value[(0, 0)], value[(0, 1)], value[(0, 2)]);
-        let v1 = Vert3::new(value[(1, 0)], value[(1, 1)], value[(1, 2)]);
-        let v2 = Vert3::new(value[(2, 0)], value[(2, 1)], value[(2, 2)]);
+    fn from(value: Matrix3<T>) -> Self {
+        let v0 = Vertex3::new(value[(0, 0)], value[(0, 1)], value[(0, 2)]);
+        let v1 = Vertex3::new(value[(1, 0)], value[(1, 1)], value[(1, 2)]);
+        let v2 = Vertex3::new(value[(2, 0)], value[(2, 1)], value[(2, 2)]);
         [v0, v1, v2]
     }
 }
-impl From<&Matr3> for [Vert3; 3] {
+impl<T: Scalar> From<&Matrix3<T>> for [Vertex3<T>; 3] {
     #[inline]
-    fn from(value: &Matr3) -> Self {
-        let v0 = Vert3::new(value[(0, 0)], value[(0, 1)], value[(0, 2)]);
-        let v1 = Vert3::new(value[(1, 0)], value[(1, 1)], value[(1, 2)]);
-        let v2 = Vert3::new(value[(2, 0)], value[(2, 1)], value[(2, 2)]);
+    fn from(value: &Matrix3<T>) -> Self {
+        let v0 = Vertex3::new(value[(0, 0)], value[(0, 1)], value[(0, 2)]);
+        let v1 = Vertex3::new(value[(1, 0)], value[(1, 1)], value[(1, 2)]);
+        let v2 = Vertex3::new(value[(2, 0)], value[(2, 1)], value[(2, 2)]);
         [v0, v1, v2]
     }
 }
@@ -250,82 +260,85 @@ pub trait Axis {
     type Primary;
     type Secondary;
 }
+#[derive(Debug, Clone, Copy, Default)]
 pub struct X;
 impl Axis for X {
     type Primary = Y;
     type Secondary = Z;
 }
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Y;
 impl Axis for Y {
     type Primary = X;
     type Secondary = Z;
 }
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Z;
 impl Axis for Z {
     type Primary = X;
     type Secondary = Y;
 }
-pub struct RotationAxes<T, U>{
-    rad: f32,
+pub struct RotationAxes<T, U, S = f32>{
+    rad: S,
     axes: PhantomData<(T, U)>
 }
 
-impl<T, U> RotationAxes<T, U> {
-    pub fn new_rad(rad: f32) -> RotationAxes<T, U> {
+impl<T, U, S: Scalar> RotationAxes<T, U, S> {
+    pub fn new_rad(rad: S) -> RotationAxes<T, U, S> {
         RotationAxes { rad, axes: PhantomData }
     }
-    pub fn new_deg(deg: f32) -> RotationAxes<T, U> {
+    pub fn new_deg(deg: S) -> RotationAxes<T, U, S> {
         RotationAxes::new_rad(deg.to_radians())
     }
 }
-impl RotationAxes<Y, Z> {
+impl<S: Scalar> RotationAxes<Y, Z, S> {
     #[inline]
-    pub fn yy(&self) -> f32 {
+    pub fn yy(&self) -> S {
         self.rad.cos()
     }
     #[inline]
-    pub fn yz(&self) -> f32 {
+    pub fn yz(&self) -> S {
         -self.rad.sin()
     }
     #[inline]
-    pub fn zy(&self) -> f32 {
+    pub fn zy(&self) -> S {
         self.rad.sin()
     }
 }
-impl RotationAxes<X, Z> {
+impl<S: Scalar> RotationAxes<X, Z, S> {
     #[inline]
-    fn xz(&self) -> f32 {
+    fn xz(&self) -> S {
         self.rad.sin()
     }
     #[inline]
-    fn zx(&self) -> f32 {
+    fn zx(&self) -> S {
         -self.rad.sin()
     }
 
 }
-impl RotationAxes<X, Y> {
+impl<S: Scalar> RotationAxes<X, Y, S> {
     #[inline]
-    fn yy(&self) -> f32 {
+    fn yy(&self) -> S {
         self.rad.cos()
     }
     #[inline]
-    fn xy(&self) -> f32 {
+    fn xy(&self) -> S {
         -self.rad.sin()   
     }
     #[inline]
-    fn yx(&self) -> f32 {
+    fn yx(&self) -> S {
         self.rad.sin()
     }
 }
-impl<T> RotationAxes<X, T> {
+impl<T, S: Scalar> RotationAxes<X, T, S> {
     #[inline]
-    fn xx(&self) -> f32 {
+    fn xx(&self) -> S {
         self.rad.cos()
     }
 }
-impl<T> RotationAxes<T, Z> {
+impl<T, S: Scalar> RotationAxes<T, Z, S> {
     #[inline]
-    fn zz(&self) -> f32 {
+    fn zz(&self) -> S {
         self.rad.cos()
     }
 }
@@ -335,24 +348,45 @@ impl<T> RotationAxes<T, Z> {
 //         self.rad.cos()
 //     }
 // }
-pub struct RotationAbout<T> {
-    rad: f32,
-    rotation_axis_marker:PhantomData<T>
+/// A rotation of `rad` about `T`, either one of the axis markers [`X`], [`Y`]
+/// and [`Z`], or a unit [`Vertex4`] vector only known at runtime
+pub struct RotationAbout<T, S = f32> {
+    rad: S,
+    axis: T
 }
-impl<T> RotationAbout<T> {
+impl<T: Axis + Default, S: Scalar> RotationAbout<T, S> {
     #[inline]
-    pub fn new_rad(rad: f32) -> RotationAbout<T> where T: Axis {
-        RotationAbout { rad , rotation_axis_marker:PhantomData  }
+    pub fn new_rad(rad: S) -> RotationAbout<T, S> {
+        RotationAbout { rad, axis: T::default() }
     }
-    pub fn new_deg(deg: f32) -> RotationAbout<T> where T: Axis {
+    pub fn new_deg(deg: S) -> RotationAbout<T, S> {
         RotationAbout::new_rad(deg.to_radians())
     }
 }
-pub trait IntoAxes<T> where T: Axis {
-    fn into_axes(self) -> RotationAxes<T::Primary, T::Secondary>;
+impl<S: Scalar> RotationAbout<Vertex4<S>, S>
+where
+    Simd<S, 4>: SimdOps<S, 4>,
+{
+    /// `axis` must be a unit vector
+    #[inline]
+    pub fn from_axis_rad(axis: Vertex4<S>, rad: S) -> RotationAbout<Vertex4<S>, S> {
+        debug_assert!(axis.is_vector(), "rotation axis must be a vector");
+        debug_assert!((axis.mag() - S::ONE).abs() < S::EPSILON, "rotation axis must be a unit vector");
+        RotationAbout { rad, axis }
+    }
+    pub fn from_axis_deg(axis: Vertex4<S>, deg: S) -> RotationAbout<Vertex4<S>, S> {
+        RotationAbout::from_axis_rad(axis, deg.to_radians())
+    }
+    #[inline]
+    pub fn axis(&self) -> &Vertex4<S> {
+        &self.axis
+    }
+}
+pub trait IntoAxes<T, S = f32> where T: Axis {
+    fn into_axes(self) -> RotationAxes<T::Primary, T::Secondary, S>;
 }
-impl<T> IntoAxes<T> for RotationAbout<T> where T: Axis {
-    fn into_axes(self) -> RotationAxes<T::Primary, T::Secondary> {
+impl<T, S: Scalar> IntoAxes<T, S> for RotationAbout<T, S> where T: Axis {
+    fn into_axes(self) -> RotationAxes<T::Primary, T::Secondary, S> {
         RotationAxes::new_rad(self.rad)
     }
 }
@@ -363,18 +397,18 @@ impl<T, Ax> ShearOf<T, Ax> where Ax: Axis {
         ShearOf(value, PhantomData)
     }
 }
-pub struct ShearingProportion<Ax: Axis> {
-    ratio_to_primary: ShearOf<f32, Ax::Primary>,
-    ratio_to_secondary: ShearOf<f32, Ax::Secondary>,
+pub struct ShearingProportion<Ax: Axis, S = f32> {
+    ratio_to_primary: ShearOf<S, Ax::Primary>,
+    ratio_to_secondary: ShearOf<S, Ax::Secondary>,
     axis_marker: PhantomData<Ax>
 }
-impl<Ax: Axis> Default for ShearingProportion<Ax> where Ax::Primary: Axis, Ax::Secondary: Axis {
+impl<Ax: Axis, S: Scalar> Default for ShearingProportion<Ax, S> where Ax::Primary: Axis, Ax::Secondary: Axis {
     fn default() -> Self {
-        ShearingProportion::new(f32::default(), f32::default())
+        ShearingProportion::new(S::default(), S::default())
     }
 }
-impl<Ax: Axis> ShearingProportion<Ax> {
-    pub fn new(to_primary: f32, to_secondary: f32) -> ShearingProportion<Ax>
+impl<Ax: Axis, S: Scalar> ShearingProportion<Ax, S> {
+    pub fn new(to_primary: S, to_secondary: S) -> ShearingProportion<Ax, S>
     where 
         Ax::Primary: Axis, 
         Ax::Secondary: Axis
@@ -385,134 +419,241 @@ impl<Ax: Axis> ShearingProportion<Ax> {
             axis_marker: PhantomData
         }
     }
+    #[inline]
+    pub fn to_primary(&self) -> S {
+        self.ratio_to_primary.0
+    }
+    #[inline]
+    pub fn to_secondary(&self) -> S {
+        self.ratio_to_secondary.0
+    }
+}
+impl<Ax: Axis, S: Scalar> Debug for ShearingProportion<Ax, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ShearingProportion")
+            .field("to_primary", &self.to_primary())
+            .field("to_secondary", &self.to_secondary())
+            .finish()
+    }
 }
 #[derive(Debug)]
-pub struct Matr4(f32x16);
-impl AsArray<f32, 16> for Matr4 {
-    fn as_array(&self) -> &[f32; 16] {
+pub struct Matrix4<T: Scalar>(Simd<T, 16>);
+pub type Matr4 = Matrix4<f32>;
+pub type DMatr4 = Matrix4<f64>;
+impl<T: Scalar> AsArray<T, 16> for Matrix4<T> {
+    fn as_array(&self) -> &[T; 16] {
         self.0.as_array()
     }
 }
-impl FromArray<f32, 16> for Matr4 {
+impl<T: Scalar> FromArray<T, 16> for Matrix4<T> {
     #[inline]
-    fn from_array(array: [f32; 16]) -> Matr4 {
-        Matr4(f32x16::from_array(array))
+    fn from_array(array: [T; 16]) -> Matrix4<T> {
+        Matrix4(Simd::from_array(array))
     }
 }
-impl FromSlice<f32> for Matr4 {
+impl<T: Scalar> FromSlice<T> for Matrix4<T> {
     #[inline]
-    fn from_slice(slice: &[f32]) -> Matr4 {
-        Matr4(f32x16::from_slice(slice))
+    fn from_slice(slice: &[T]) -> Matrix4<T> {
+        Matrix4(Simd::from_slice(slice))
     }
 }
-impl Matr4 {
+impl<T: Scalar> Matrix4<T> {
     #[inline]
-    pub const fn translation(tx: f32, ty: f32, tz: f32) -> Matr4 {
+    pub const fn translation(tx: T, ty: T, tz: T) -> Matrix4<T> {
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
-            pub const fn create(tx: f32, ty: f32, tz: f32) -> Matr4 {
-                Matr4(f32x16::from_array([
-                    1., 0., 0., tx, 
-                    0., 1., 0., ty,
-                    0., 0., 1., tz,
-                    0., 0., 0., 1.
+            use super::{Matrix4, Scalar, Simd};
+            pub const fn create<T: Scalar>(tx: T, ty: T, tz: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
+                Matrix4(Simd::from_array([
+                    l, o, o, tx, 
+                    o, l, o, ty,
+                    o, o, l, tz,
+                    o, o, o, l
                 ]))
             }
         }
         inner::create(tx, ty, tz)
     }
     #[inline]
-    pub const fn scaling(sx: f32, sy: f32, sz: f32) -> Matr4 {
+    pub const fn scaling(sx: T, sy: T, sz: T) -> Matrix4<T> {
 
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
-            pub const fn create(sx: f32, sy: f32, sz: f32) -> Matr4 {
-                Matr4(f32x16::from_array([
-                    sx, 0., 0., 0., 
-                    0., sy, 0., 0.,
-                    0., 0., sz, 0.,
-                    0., 0., 0., 1.
+            use super::{Matrix4, Scalar, Simd};
+            pub const fn create<T: Scalar>(sx: T, sy: T, sz: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
+                Matrix4(Simd::from_array([
+                    sx, o, o, o, 
+                    o, sy, o, o,
+                    o, o, sz, o,
+                    o, o, o, l
                 ]))
             }
         }
         inner::create(sx, sy, sz)
     }
     #[inline]
-    pub fn rotation_x_rad(rad: f32) -> Matr4 {
+    pub fn rotation_x_rad(rad: T) -> Matrix4<T> {
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
-            pub const fn create(yy: f32, yz: f32, zy: f32, zz: f32) -> Matr4 {
-                Matr4(f32x16::from_array([
-                    1., 0., 0., 0., 
-                    0., yy, yz, 0.,
-                    0., zy, zz, 0.,
-                    0., 0., 0., 1.
+            use super::{Matrix4, Scalar, Simd};
+            pub const fn create<T: Scalar>(yy: T, yz: T, zy: T, zz: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
+                Matrix4(Simd::from_array([
+                    l, o, o, o, 
+                    o, yy, yz, o,
+                    o, zy, zz, o,
+                    o, o, o, l
                 ]))
             }
         }
-        let rotation: RotationAbout<X> = RotationAbout::new_rad(rad);
+        let rotation: RotationAbout<X, T> = RotationAbout::new_rad(rad);
         let axes = rotation.into_axes();
         inner::create(axes.yy(), axes.yz(), axes.zy(), axes.zz())
     }
     #[inline]
-    pub fn rotation_x_deg(deg: f32) -> Matr4 {
-        Matr4::rotation_x_rad(deg.to_radians())
+    pub fn rotation_x_deg(deg: T) -> Matrix4<T> {
+        Matrix4::rotation_x_rad(deg.to_radians())
     }
 
     #[inline]
-    pub fn rotation_y_rad(rad: f32) -> Matr4 {
-        let rotation: RotationAbout<Y> = RotationAbout::new_rad(rad);
+    pub fn rotation_y_rad(rad: T) -> Matrix4<T> {
+        let rotation: RotationAbout<Y, T> = RotationAbout::new_rad(rad);
         let axes = rotation.into_axes();
 
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
+            use super::{Matrix4, Scalar, Simd};
             #[inline]
-            pub fn create(xx: f32, xz: f32, zx: f32, zz: f32) -> Matr4 {
+            pub fn create<T: Scalar>(xx: T, xz: T, zx: T, zz: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
                 let array = 
                 [
-                    xx, 0., xz, 0., 
-                    0., 1., 0., 0.,
-                    zx, 0., zz, 0.,
-                    0., 0., 0., 1.
+                    xx, o, xz, o, 
+                    o, l, o, o,
+                    zx, o, zz, o,
+                    o, o, o, l
                 ];
-                Matr4(f32x16::from_array(array))
+                Matrix4(Simd::from_array(array))
             }
         }
         inner::create(axes.xx(), axes.xz(), axes.zx(), axes.zz())
     }
     #[inline]
-    pub fn rotation_y_deg(deg: f32) -> Matr4 {
-        Matr4::rotation_y_rad(deg.to_radians())
+    pub fn rotation_y_deg(deg: T) -> Matrix4<T> {
+        Matrix4::rotation_y_rad(deg.to_radians())
     }
     #[inline]
-    pub fn rotation_z_rad(rad: f32) -> Matr4 {
-        let rotation: RotationAbout<Z> = RotationAbout::new_rad(rad);
+    pub fn rotation_z_rad(rad: T) -> Matrix4<T> {
+        let rotation: RotationAbout<Z, T> = RotationAbout::new_rad(rad);
         let axes = rotation.into_axes();
 
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
+            use super::{Matrix4, Scalar, Simd};
             #[inline]
-            pub fn create(xx: f32, xy: f32, yx: f32, yy: f32) -> Matr4 {
+            pub fn create<T: Scalar>(xx: T, xy: T, yx: T, yy: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
                 let array = 
                 [
-                    xx, xy, 0., 0., 
-                    yx, yy, 0., 0.,
-                    0., 0., 1., 0.,
-                    0., 0., 0., 1.
+                    xx, xy, o, o, 
+                    yx, yy, o, o,
+                    o, o, l, o,
+                    o, o, o, l
                 ];
-                Matr4(f32x16::from_array(array))
+                Matrix4(Simd::from_array(array))
             }
         }
         inner::create(axes.xx(), axes.xy(), axes.yx(), axes.yy())
     }
     #[inline]
-    pub fn rotation_z_deg(deg: f32) -> Matr4 {
-        Matr4::rotation_z_rad(deg.to_radians())
+    pub fn rotation_z_deg(deg: T) -> Matrix4<T> {
+        Matrix4::rotation_z_rad(deg.to_radians())
+    }
+    /// Rotation of `rad` about `axis`, which is normalized first
+    #[inline]
+    pub fn rotation_axis_angle(axis: Vertex4<T>, rad: T) -> Matrix4<T>
+    where
+        Simd<T, 4>: SimdOps<T, 4>,
+    {
+        Matrix4::from(RotationAbout::from_axis_rad(axis.norm(), rad))
+    }
+    /// Right-handed view transform of an eye at `from` looking at `to`,
+    /// mapping the forward direction onto -z. `up` only needs to be roughly
+    /// up, it must not be parallel to the forward direction.
+    #[inline]
+    pub fn view_transform(from: Vertex4<T>, to: Vertex4<T>, up: Vertex4<T>) -> Matrix4<T>
+    where
+        Simd<T, 4>: SimdOps<T, 4>,
+    {
+        let forward = (to - from).norm();
+        let left = Cross::cross(&forward, &up.norm()).norm();
+        let true_up = Cross::cross(&left, &forward);
+        view_orientation([left, true_up, -forward], &from)
+    }
+    /// Left-handed counterpart of [`Matrix4::view_transform`], mapping the
+    /// forward direction onto +z
+    #[inline]
+    pub fn view_transform_lh(from: Vertex4<T>, to: Vertex4<T>, up: Vertex4<T>) -> Matrix4<T>
+    where
+        Simd<T, 4>: SimdOps<T, 4>,
+    {
+        let forward = (to - from).norm();
+        let right = Cross::cross(&up.norm(), &forward).norm();
+        let true_up = Cross::cross(&forward, &right);
+        view_orientation([right, true_up, forward], &from)
+    }
+    /// Right-handed perspective projection into OpenGL clip space, where depth
+    /// goes from -1 at `near` to 1 at `far`. `fov_y` is the vertical field of
+    /// view in radians, `aspect` is width over height.
+    #[inline]
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let range = near - far;
+        perspective_from(fov_y, aspect, (far + near) / range, T::TWO * far * near / range)
+    }
+    /// [`Matrix4::perspective`] into Vulkan/D3D clip space, where depth goes
+    /// from 0 at `near` to 1 at `far`
+    #[inline]
+    pub fn perspective_zo(fov_y: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let range = near - far;
+        perspective_from(fov_y, aspect, far / range, far * near / range)
+    }
+    /// [`Matrix4::perspective_zo`] with reversed depth, 1 at `near` and 0 at `far`
+    #[inline]
+    pub fn perspective_reversed_z(fov_y: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let range = far - near;
+        perspective_from(fov_y, aspect, near / range, far * near / range)
+    }
+    /// [`Matrix4::perspective`] with the far plane at infinity
+    #[inline]
+    pub fn perspective_infinite(fov_y: T, aspect: T, near: T) -> Matrix4<T> {
+        perspective_from(fov_y, aspect, -T::ONE, -T::TWO * near)
+    }
+    /// [`Matrix4::perspective_zo`] with the far plane at infinity
+    #[inline]
+    pub fn perspective_infinite_zo(fov_y: T, aspect: T, near: T) -> Matrix4<T> {
+        perspective_from(fov_y, aspect, -T::ONE, -near)
+    }
+    /// [`Matrix4::perspective_reversed_z`] with the far plane at infinity, where
+    /// depth approaches 0
+    #[inline]
+    pub fn perspective_infinite_reversed_z(fov_y: T, aspect: T, near: T) -> Matrix4<T> {
+        perspective_from(fov_y, aspect, T::ZERO, near)
+    }
+    /// Right-handed orthographic projection of the box into OpenGL clip space,
+    /// depth going from -1 at `near` to 1 at `far`
+    #[inline]
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let depth = near - far;
+        orthographic_from([left, right, bottom, top], T::TWO / depth, (far + near) / depth)
+    }
+    /// [`Matrix4::orthographic`] into Vulkan/D3D clip space, depth going from
+    /// 0 at `near` to 1 at `far`
+    #[inline]
+    pub fn orthographic_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let depth = near - far;
+        orthographic_from([left, right, bottom, top], T::ONE / depth, near / depth)
     }
     #[inline]
     pub const fn shearing(
@@ -520,42 +661,130 @@ impl Matr4 {
             ratio_to_primary: ShearOf(xy, _xy),
             ratio_to_secondary: ShearOf(xz, _xz),
             ..
-        }: ShearingProportion<X>, 
+        }: ShearingProportion<X, T>, 
         ShearingProportion {
             ratio_to_primary: ShearOf(yx, _yx),
             ratio_to_secondary: ShearOf(yz, _yz),
             ..
-        }: ShearingProportion<Y>,
+        }: ShearingProportion<Y, T>,
         ShearingProportion {
             ratio_to_primary: ShearOf(zx, _zx),
             ratio_to_secondary: ShearOf(zy, _zy),
             ..
-        }: ShearingProportion<Z>) -> Matr4 {
+        }: ShearingProportion<Z, T>) -> Matrix4<T> {
         #[rustfmt::skip]
         mod inner {
-            use super::{Matr4, f32x16};
+            use super::{Matrix4, Scalar, Simd};
             #[inline]
-            pub const fn create(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Matr4 {
+            pub const fn create<T: Scalar>(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
                 let array = [
-                    1., xy, xz, 0., 
-                    yx, 1., yz, 0.,
-                    zx, zy, 1., 0.,
-                    0., 0., 0., 1.
+                    l, xy, xz, o, 
+                    yx, l, yz, o,
+                    zx, zy, l, o,
+                    o, o, o, l
                 ];
-                Matr4(f32x16::from_array(array))
+                Matrix4(Simd::from_array(array))
             }
         }
         inner::create(xy, xz, yx, yz, zx, zy)
     }
 }
+impl<T: Scalar> From<RotationAbout<X, T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: RotationAbout<X, T>) -> Self {
+        Matrix4::rotation_x_rad(value.rad)
+    }
+}
+impl<T: Scalar> From<RotationAbout<Y, T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: RotationAbout<Y, T>) -> Self {
+        Matrix4::rotation_y_rad(value.rad)
+    }
+}
+impl<T: Scalar> From<RotationAbout<Z, T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: RotationAbout<Z, T>) -> Self {
+        Matrix4::rotation_z_rad(value.rad)
+    }
+}
+impl<T: Scalar> From<RotationAbout<Vertex4<T>, T>> for Matrix4<T> {
+    /// Rodrigues' rotation formula, `R = cI + s[k]× + (1 - c)kkᵀ`
+    #[inline]
+    fn from(value: RotationAbout<Vertex4<T>, T>) -> Self {
+        #[rustfmt::skip]
+        mod inner {
+            use super::{Matrix4, Scalar, Simd};
+            #[inline]
+            pub fn create<T: Scalar>(x: T, y: T, z: T, c: T, s: T) -> Matrix4<T> {
+                let (o, l) = (T::ZERO, T::ONE);
+                let t = l - c;
+                let array = [
+                    c + x * x * t, x * y * t - z * s, x * z * t + y * s, o,
+                    x * y * t + z * s, c + y * y * t, y * z * t - x * s, o,
+                    x * z * t - y * s, y * z * t + x * s, c + z * z * t, o,
+                    o, o, o, l
+                ];
+                Matrix4(Simd::from_array(array))
+            }
+        }
+        let RotationAbout { rad, axis } = value;
+        inner::create(axis.x(), axis.y(), axis.z(), rad.cos(), rad.sin())
+    }
+}
+// The camera axes become the rows, after moving the eye to the origin
+#[inline]
+fn view_orientation<T: Scalar>(axes: [Vertex4<T>; 3], from: &Vertex4<T>) -> Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    let [x, y, z] = axes;
+    let (o, l) = (T::ZERO, T::ONE);
+    #[rustfmt::skip]
+    let orientation = Matrix4(Simd::from_array([
+        x.x(), x.y(), x.z(), o,
+        y.x(), y.y(), y.z(), o,
+        z.x(), z.y(), z.z(), o,
+        o, o, o, l,
+    ]));
+    orientation * Matrix4::translation(-from.x(), -from.y(), -from.z())
+}
+// `zz` and `zw` map the view space depth, the camera looks down -z
+#[inline]
+fn perspective_from<T: Scalar>(fov_y: T, aspect: T, zz: T, zw: T) -> Matrix4<T> {
+    let focal = T::ONE / (fov_y * T::HALF).tan();
+    let (o, l) = (T::ZERO, T::ONE);
+    #[rustfmt::skip]
+    let array = [
+        focal / aspect, o, o, o,
+        o, focal, o, o,
+        o, o, zz, zw,
+        o, o, -l, o,
+    ];
+    Matrix4(Simd::from_array(array))
+}
+#[inline]
+fn orthographic_from<T: Scalar>([left, right, bottom, top]: [T; 4], zz: T, zw: T) -> Matrix4<T> {
+    let (width, height) = (right - left, top - bottom);
+    let (o, l, two) = (T::ZERO, T::ONE, T::TWO);
+    #[rustfmt::skip]
+    let array = [
+        two / width, o, o, -(right + left) / width,
+        o, two / height, o, -(top + bottom) / height,
+        o, o, zz, zw,
+        o, o, o, l,
+    ];
+    Matrix4(Simd::from_array(array))
+}
 pub(crate) const T_SWIZZLE_4: [usize; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
-impl Matrix<4> for Matr4 {
-    type Vert = Vert4;
+impl<T: Scalar> Matrix<4> for Matrix4<T> {
+    type Vert = Vertex4<T>;
     
     #[inline]
     fn identity() -> Self {
-        Self(f32x16::from_array([
-            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        let (o, l) = (T::ZERO, T::ONE);
+        Self(Simd::from_array([
+            l, o, o, o, o, l, o, o, o, o, l, o, o, o, o, l,
         ]))
     }
     #[inline]
@@ -563,50 +792,53 @@ impl Matrix<4> for Matr4 {
         Self(simd_swizzle!(self.0, T_SWIZZLE_4))
     }
 }
-impl Index<(usize, usize)> for Matr4 {
-    type Output = f32;
+impl<T: Scalar> Index<(usize, usize)> for Matrix4<T> {
+    type Output = T;
     #[inline]
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         &self.0[index.1 + (4 * index.0)]
     }
 }
-impl IndexMut<(usize, usize)> for Matr4 {
+impl<T: Scalar> IndexMut<(usize, usize)> for Matrix4<T> {
     #[inline]
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         &mut self.0[index.1 + (4 * index.0)]
     }
 }
 // impl From<Mat4> for [Vert4; 4] {}
-impl From<Matr4> for [Vert4; 4] {
+impl<T: Scalar> From<Matrix4<T>> for [Vertex4<T>; 4] {
     #[inline]
-    fn from(value: Matr4) -> Self {
-        let v0 = Vert4::new(value[(0, 0)], value[(0, 1)], value[(0, 2)], value[(0, 3)]);
-        let v1 = Vert4::new(value[(1, 0)], value[(1, 1)], value[(1, 2)], value[(1, 3)]);
-        let v2 = Vert4::new(value[(2, 0)], value[(2, 1)], value[(2, 2)], value[(2, 3)]);
-        let v3 = Vert4::new(value[(3, 0)], value[(3, 1)], value[(3, 2)], value[(3, 3)]);
+    fn from(value: Matrix4<T>) -> Self {
+        let v0 = Vertex4::new(value[(0, 0)], value[(0, 1)], value[(0, 2)], value[(0, 3)]);
+        let v1 = Vertex4::new(value[(1, 0)], value[(1, 1)], value[(1, 2)], value[(1, 3)]);
+        let v2 = Vertex4::new(value[(2, 0)], value[(2, 1)], value[(2, 2)], value[(2, 3)]);
+        let v3 = Vertex4::new(value[(3, 0)], value[(3, 1)], value[(3, 2)], value[(3, 3)]);
         [v0, v1, v2, v3]
     }
 }
-impl From<&Matr4> for [Vert4; 4] {
+impl<T: Scalar> From<&Matrix4<T>> for [Vertex4<T>; 4] {
     #[inline]
-    fn from(value: &Matr4) -> Self {
-        let v0 = Vert4::new(value[(0, 0)], value[(0, 1)], value[(0, 2)], value[(0, 3)]);
-        let v1 = Vert4::new(value[(1, 0)], value[(1, 1)], value[(1, 2)], value[(1, 3)]);
-        let v2 = Vert4::new(value[(2, 0)], value[(2, 1)], value[(2, 2)], value[(2, 3)]);
-        let v3 = Vert4::new(value[(3, 0)], value[(3, 1)], value[(3, 2)], value[(3, 3)]);
+    fn from(value: &Matrix4<T>) -> Self {
+        let v0 = Vertex4::new(value[(0, 0)], value[(0, 1)], value[(0, 2)], value[(0, 3)]);
+        let v1 = Vertex4::new(value[(1, 0)], value[(1, 1)], value[(1, 2)], value[(1, 3)]);
+        let v2 = Vertex4::new(value[(2, 0)], value[(2, 1)], value[(2, 2)], value[(2, 3)]);
+        let v3 = Vertex4::new(value[(3, 0)], value[(3, 1)], value[(3, 2)], value[(3, 3)]);
         [v0, v1, v2, v3]
     }
 }
-impl AsColumns<4> for Matr4 {}
-impl Mul<Matr4> for Matr4 {
-    type Output = Matr4;
+impl<T: Scalar> AsColumns<4> for Matrix4<T> {}
+impl<T: Scalar> Mul<Matrix4<T>> for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Matrix4<T>;
     #[inline]
-    fn mul(self, rhs: Matr4) -> Self::Output {
+    fn mul(self, rhs: Matrix4<T>) -> Self::Output {
         // M[(r, c)] = A[(r, 0)] * B[(0, c)] (= B[0 + 4c])
         //           + A[(r, 1)] * B[(1, c)]
         //           + A[(r, 2)] * B[(2, c)]
         //           + A[(r, 3)] * B[(3, c)]
-        let mut output: Matr4 = Matr4::identity();
+        let mut output: Matrix4<T> = Matrix4::identity();
         let rows = self.as_row_vectors();
         let cols = rhs.as_column_vectors();
         for (row_idx, row) in rows.iter().enumerate() {
@@ -617,15 +849,18 @@ impl Mul<Matr4> for Matr4 {
         output
     }
 }
-impl Mul<&Matr4> for Matr4 {
-    type Output = Matr4;
+impl<T: Scalar> Mul<&Matrix4<T>> for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Matrix4<T>;
     #[inline]
-    fn mul(self, rhs: &Matr4) -> Self::Output {
+    fn mul(self, rhs: &Matrix4<T>) -> Self::Output {
         // M[(r, c)] = A[(r, 0)] * B[(0, c)] (= B[0 + 4c])
         //           + A[(r, 1)] * B[(1, c)]
         //           + A[(r, 2)] * B[(2, c)]
         //           + A[(r, 3)] * B[(3, c)]
-        let mut output: Matr4 = Matr4::identity();
+        let mut output: Matrix4<T> = Matrix4::identity();
         let rows = self.as_row_vectors();
         let cols = rhs.as_column_vectors();
         for (row_idx, row) in rows.iter().enumerate() {
@@ -636,15 +871,18 @@ impl Mul<&Matr4> for Matr4 {
         output
     }
 }
-impl Mul<Matr4> for &Matr4 {
-    type Output = Matr4;
+impl<T: Scalar> Mul<Matrix4<T>> for &Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Matrix4<T>;
     #[inline]
-    fn mul(self, rhs: Matr4) -> Self::Output {
+    fn mul(self, rhs: Matrix4<T>) -> Self::Output {
         // M[(r, c)] = A[(r, 0)] * B[(0, c)] (= B[0 + 4c])
         //           + A[(r, 1)] * B[(1, c)]
         //           + A[(r, 2)] * B[(2, c)]
         //           + A[(r, 3)] * B[(3, c)]
-        let mut output: Matr4 = Matr4::identity();
+        let mut output: Matrix4<T> = Matrix4::identity();
         let rows = self.as_row_vectors();
         let cols = rhs.as_column_vectors();
         for (row_idx, row) in rows.iter().enumerate() {
@@ -655,15 +893,18 @@ impl Mul<Matr4> for &Matr4 {
         output
     }
 }
-impl Mul<&Matr4> for &Matr4 {
-    type Output = Matr4;
+impl<T: Scalar> Mul<&Matrix4<T>> for &Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Matrix4<T>;
     #[inline]
-    fn mul(self, rhs: &Matr4) -> Self::Output {
+    fn mul(self, rhs: &Matrix4<T>) -> Self::Output {
         // M[(r, c)] = A[(r, 0)] * B[(0, c)] (= B[0 + 4c])
         //           + A[(r, 1)] * B[(1, c)]
         //           + A[(r, 2)] * B[(2, c)]
         //           + A[(r, 3)] * B[(3, c)]
-        let mut output: Matr4 = Matr4::identity();
+        let mut output: Matrix4<T> = Matrix4::identity();
         let rows = self.as_row_vectors();
         let cols = rhs.as_column_vectors();
         for (row_idx, row) in rows.iter().enumerate() {
@@ -674,10 +915,13 @@ impl Mul<&Matr4> for &Matr4 {
         output
     }
 }
-impl Mul<Vert4> for Matr4 {
-    type Output = Vert4;
+impl<T: Scalar> Mul<Vertex4<T>> for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
     #[inline]
-    fn mul(self, rhs: Vert4) -> Self::Output {
+    fn mul(self, rhs: Vertex4<T>) -> Self::Output {
         // let array = core::array::from_fn(|y_idx| 
         //     {
         //         let y_offset = y_idx * 4;
@@ -696,13 +940,13 @@ impl Mul<Vert4> for Matr4 {
         //         f32x4::from_array(row_array).reduce_sum()
         //     }
         // );
-        let arr: &[f32; 16] = self.0.as_array();
+        let arr: &[T; 16] = self.0.as_array();
 
-        let arr_0 =Vert4::from_slice(unsafe {arr.get_unchecked(0..4)}) * &rhs;
-        let arr_1 = Vert4::from_slice(unsafe {arr.get_unchecked(4..8)}) * &rhs;
-        let arr_2 = Vert4::from_slice(unsafe {arr.get_unchecked(8..12)}) * &rhs;
-        let arr_3 = Vert4::from_slice(unsafe {arr.get_unchecked(12..16)}) * &rhs;
-        Vert4::from_array([
+        let arr_0 =Vertex4::from_slice(unsafe {arr.get_unchecked(0..4)}) * rhs;
+        let arr_1 = Vertex4::from_slice(unsafe {arr.get_unchecked(4..8)}) * rhs;
+        let arr_2 = Vertex4::from_slice(unsafe {arr.get_unchecked(8..12)}) * rhs;
+        let arr_3 = Vertex4::from_slice(unsafe {arr.get_unchecked(12..16)}) * rhs;
+        Vertex4::from_array([
                     arr_0.reduce_sum(),
                     arr_1.reduce_sum(),
                     arr_2.reduce_sum(),
@@ -710,11 +954,14 @@ impl Mul<Vert4> for Matr4 {
         ])
     }
 }
-impl Mul<&Vert4> for Matr4 {
-    type Output = Vert4;
+impl<T: Scalar> Mul<&Vertex4<T>> for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
     #[inline]
-    fn mul(self, rhs: &Vert4) -> Self::Output {
-        let mut output: Vert4 = Vert4::new(0.0, 0.0, 0.0, 0.0);
+    fn mul(self, rhs: &Vertex4<T>) -> Self::Output {
+        let mut output: Vertex4<T> = Vertex4::ZERO;
         let rows = self.as_row_vectors();
         for (idx, row) in rows.iter().enumerate() {
             output[idx] = (row * rhs).reduce_sum();
@@ -722,23 +969,29 @@ impl Mul<&Vert4> for Matr4 {
         output
     }
 }
-impl Mul<Vert4> for &Matr4 {
-    type Output = Vert4;
+impl<T: Scalar> Mul<Vertex4<T>> for &Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
     #[inline]
-    fn mul(self, rhs: Vert4) -> Self::Output {
-        let mut output: Vert4 = Vert4::new(0.0, 0.0, 0.0, 0.0);
+    fn mul(self, rhs: Vertex4<T>) -> Self::Output {
+        let mut output: Vertex4<T> = Vertex4::ZERO;
         let rows = self.as_row_vectors();
         for (idx, row) in rows.iter().enumerate() {
-            output[idx] = (row * &rhs).reduce_sum();
+            output[idx] = (row * rhs).reduce_sum();
         }
         output
     }
 }
-impl Mul<&Vert4> for &Matr4 {
-    type Output = Vert4;
+impl<T: Scalar> Mul<&Vertex4<T>> for &Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
     #[inline]
-    fn mul(self, rhs: &Vert4) -> Self::Output {
-        let mut output: Vert4 = Vert4::new(0.0, 0.0, 0.0, 0.0);
+    fn mul(self, rhs: &Vertex4<T>) -> Self::Output {
+        let mut output: Vertex4<T> = Vertex4::ZERO;
         let rows = self.as_row_vectors();
         for (idx, row) in rows.iter().enumerate() {
             output[idx] = (row * rhs).reduce_sum();
@@ -746,7 +999,10 @@ impl Mul<&Vert4> for &Matr4 {
         output
     }
 }
-impl PartialEq for Matr4 {
+impl<T: Scalar> PartialEq for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
     fn eq(&self, other: &Self) -> bool {
         self.as_row_vectors()
             .iter()
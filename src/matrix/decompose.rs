@@ -0,0 +1,102 @@
+//! Splitting an affine [`Matrix4`] into the transforms it is built from.
+//!
+//! The order is fixed to `translation * rotation * scaling * shearing`, i.e.
+//! shear first. Only the shears of `x` along `y` and `z` and of `y` along `z`
+//! are recovered: together with the scale and the rotation they already cover
+//! every invertible 3x3 part.
+
+use core::simd::Simd;
+
+use crate::matrix::{ShearingProportion, X, Y, Z};
+use crate::matrix::{FromArray, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::{Cross, Dot, Mag, Vertex4};
+
+/// The parts of an affine transform, see [`Matrix4::decompose`]
+#[derive(Debug)]
+pub struct Decomposition<T: Scalar = f32> {
+    /// Point the origin is moved to
+    pub translation: Vertex4<T>,
+    pub rotation: Quaternion<T>,
+    /// Vector of the scale along each axis, negative for reflections
+    pub scale: Vertex4<T>,
+    pub shear_x: ShearingProportion<X, T>,
+    pub shear_y: ShearingProportion<Y, T>,
+    pub shear_z: ShearingProportion<Z, T>,
+}
+impl<T: Scalar> Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    /// `None` if the bottom row is not `[0, 0, 0, 1]` or the 3x3 part is
+    /// singular
+    pub fn decompose(&self) -> Option<Decomposition<T>> {
+        if !self.is_affine() {
+            return None;
+        }
+        let [c0, c1, c2] = core::array::from_fn(|col| Vertex4::vector(self[(0, col)], self[(1, col)], self[(2, col)]));
+        // Gram-Schmidt: the columns are the rotation's columns times an
+        // upper-triangular matrix, whose diagonal is the scale. A column is
+        // degenerate when little of it is left after removing the previous
+        // ones, relative to its own length so that small scales still pass.
+        let degenerate = |scale: T, column: &Vertex4<T>| scale <= column.mag() * T::EPSILON;
+        let sx = c0.mag();
+        if degenerate(sx, &c0) {
+            return None;
+        }
+        let q0 = c0 / sx;
+        let r01 = Dot::dot(&q0, &c1);
+        let u1 = c1 - q0 * r01;
+        let sy = u1.mag();
+        if degenerate(sy, &c1) {
+            return None;
+        }
+        let q1 = u1 / sy;
+        let r02 = Dot::dot(&q0, &c2);
+        let r12 = Dot::dot(&q1, &c2);
+        let u2 = c2 - q0 * r02 - q1 * r12;
+        let mut sz = u2.mag();
+        if degenerate(sz, &c2) {
+            return None;
+        }
+        let mut q2 = u2 / sz;
+        // Move a reflection into the scale, keeping the rotation proper. Only
+        // the last row of the triangle holds `sz`, so nothing else changes.
+        if Dot::dot(&q0, &Cross::cross(&q1, &q2)) < T::ZERO {
+            q2 = -q2;
+            sz = -sz;
+        }
+        // The shear is the upper triangle divided by the scale of its row
+        let (xy, xz, yz) = (r01 / sx, r02 / sx, r12 / sy);
+        let (o, l) = (T::ZERO, T::ONE);
+        #[rustfmt::skip]
+        let rotation = Matrix4::from_array([
+            q0.x(), q1.x(), q2.x(), o,
+            q0.y(), q1.y(), q2.y(), o,
+            q0.z(), q1.z(), q2.z(), o,
+            o, o, o, l,
+        ]);
+        Some(Decomposition {
+            translation: Vertex4::point(self[(0, 3)], self[(1, 3)], self[(2, 3)]),
+            rotation: Quaternion::from(&rotation),
+            scale: Vertex4::vector(sx, sy, sz),
+            shear_x: ShearingProportion::new(xy, xz),
+            shear_y: ShearingProportion::new(o, yz),
+            shear_z: ShearingProportion::default(),
+        })
+    }
+    /// Inverse of [`Matrix4::decompose`]
+    pub fn compose(parts: &Decomposition<T>) -> Matrix4<T> {
+        let Decomposition { translation: t, rotation, scale: s, shear_x, shear_y, shear_z } = parts;
+        let shearing = Matrix4::shearing(
+            ShearingProportion::new(shear_x.to_primary(), shear_x.to_secondary()),
+            ShearingProportion::new(shear_y.to_primary(), shear_y.to_secondary()),
+            ShearingProportion::new(shear_z.to_primary(), shear_z.to_secondary()),
+        );
+        Matrix4::translation(t.x(), t.y(), t.z())
+            * Matrix4::from(rotation)
+            * Matrix4::scaling(s.x(), s.y(), s.z())
+            * shearing
+    }
+}
@@ -1,7 +1,9 @@
 use core::ops::Index;
+use core::simd::Simd;
 
 pub use crate::matrix::ops::submatrix::ConstIndex;
-use crate::matrix::{FromArray, Matr2, Matr3, Matr4};
+use crate::matrix::{FromArray, Matrix2, Matrix3, Matrix4};
+use crate::scalar::{Scalar, SimdOps};
 
 mod cofactor;
 mod inverse;
@@ -9,72 +11,77 @@ mod submatrix;
 
 pub use cofactor::Cofactor;
 
-pub trait Determinant {
-    fn determinant(&self) -> f32;
+pub trait Determinant<T: Scalar = f32> {
+    fn determinant(&self) -> T;
     fn is_invertible(&self) -> bool {
-        self.determinant() != 0.
+        self.determinant() != T::ZERO
     }
 }
-impl Determinant for [f32; 4] {
+impl<T: Scalar> Determinant<T> for [T; 4] {
     #[inline]
-    fn determinant(&self) -> f32 {
+    fn determinant(&self) -> T {
         (self[0] * self[3]) - (self[1] * self[2])
     }
 }
 
-impl Determinant for Matr2 {
+impl<T: Scalar> Determinant<T> for Matrix2<T> {
     #[inline]
-    fn determinant(&self) -> f32 {
+    fn determinant(&self) -> T {
         (self[(0, 0)] * self[(1, 1)]) - (self[(0, 1)] * self[(1, 0)])
     }
 }
-fn generic_determinant<T, const N: usize>(inval: &T) -> f32
+fn generic_determinant<S, T, const N: usize>(inval: &T) -> S
 where
-    T: ?Sized + Cofactor<N> + Index<(usize, usize), Output = f32>,
+    S: Scalar,
+    T: ?Sized + Cofactor<N, S> + Index<(usize, usize), Output = S>,
 {
-    let mut tally = 0.0;
+    let mut tally = S::ZERO;
     let row = 0;
     for col in 0..N {
         tally += inval[(row, col)] * inval.cofactor(row, col);
     }
     tally
 }
-impl Determinant for Matr3 {
+impl<T: Scalar> Determinant<T> for Matrix3<T> {
     #[inline]
-    fn determinant(&self) -> f32 {
-        generic_determinant::<Matr3, 3>(self)
+    fn determinant(&self) -> T {
+        generic_determinant::<T, Matrix3<T>, 3>(self)
     }
 }
-impl Determinant for Matr4 {
+impl<T: Scalar> Determinant<T> for Matrix4<T>
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
     #[inline]
-    fn determinant(&self) -> f32 {
-        generic_determinant::<Matr4, 4>(self)
+    fn determinant(&self) -> T {
+        inverse::determinant_4(self)
     }
 }
 
 /// The determinant of the submatrix
-pub trait Minor<const DIM: usize> {
-    fn minor(&self, row: usize, col: usize) -> f32;
+pub trait Minor<const DIM: usize, T: Scalar = f32> {
+    fn minor(&self, row: usize, col: usize) -> T;
 }
 #[inline]
-fn generic_minor<T, const N: usize, const NS: usize>(inval: &T, row: usize, col: usize) -> f32
+fn generic_minor<S, T, const N: usize, const NS: usize>(inval: &T, row: usize, col: usize) -> S
 where
+    S: Scalar,
     T: Submatrix<N>,
-    <T as Submatrix<N>>::Output: Determinant,
+    <T as Submatrix<N>>::Output: Determinant<S>,
 {
     let sm = inval.submatrix(row, col);
     sm.determinant()
 }
-impl Minor<3> for Matr3 {
+impl<T: Scalar> Minor<3, T> for Matrix3<T> {
     #[inline]
-    fn minor(&self, row: usize, col: usize) -> f32 {
-        generic_minor::<Matr3, 3, 2>(self, row, col)
+    fn minor(&self, row: usize, col: usize) -> T {
+        generic_minor::<T, Matrix3<T>, 3, 2>(self, row, col)
     }
 }
-impl Minor<4> for Matr4 {
+impl<T: Scalar> Minor<4, T> for Matrix4<T> {
     #[inline]
-    fn minor(&self, row: usize, col: usize) -> f32 {
-        generic_minor::<Matr4, 4, 3>(self, row, col)
+    fn minor(&self, row: usize, col: usize) -> T {
+        generic_minor::<T, Matrix4<T>, 4, 3>(self, row, col)
     }
 }
 
@@ -82,13 +89,14 @@ pub trait Inverse {
     type Inverted;
     fn inverse(&self) -> Option<Self::Inverted>;
 }
-fn generic_inverse<T, const DIM: usize, const N: usize>(inval: &T) -> Option<[f32; DIM]>
+fn generic_inverse<S, T, const DIM: usize, const N: usize>(inval: &T) -> Option<[S; DIM]>
 where
-    T: Determinant + Cofactor<N>,
+    S: Scalar,
+    T: Determinant<S> + Cofactor<N, S>,
 {
     let det = inval.determinant();
-    if det != 0. {
-        let mut out_arr = [0f32; DIM];
+    if det != S::ZERO {
+        let mut out_arr = [S::ZERO; DIM];
 
         let mut row = 0;
         while row < N {
@@ -105,18 +113,21 @@ where
         None
     }
 }
-impl Inverse for Matr3 {
-    type Inverted = Matr3;
+impl<T: Scalar> Inverse for Matrix3<T> {
+    type Inverted = Matrix3<T>;
     #[inline]
     fn inverse(&self) -> Option<Self> {
-        generic_inverse(self).map(Matr3::from_array)
+        generic_inverse(self).map(Matrix3::from_array)
     }
 }
-impl Inverse for Matr4 {
-    type Inverted = Matr4;
+impl<T: Scalar> Inverse for Matrix4<T>
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    type Inverted = Matrix4<T>;
     #[inline]
     fn inverse(&self) -> Option<Self> {
-        generic_inverse(self).map(Matr4::from_array)
+        inverse::inverse_4(self)
     }
 }
 
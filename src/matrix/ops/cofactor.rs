@@ -1,14 +1,15 @@
-use crate::{Matr3, Matr4, Minor};
+use crate::matrix::{Matrix3, Matrix4, Minor};
+use crate::scalar::Scalar;
 
-pub trait Cofactor<const N: usize> {
-    fn cofactor(&self, row: usize, col: usize) -> f32;
+pub trait Cofactor<const N: usize, T: Scalar = f32> {
+    fn cofactor(&self, row: usize, col: usize) -> T;
 }
-impl Cofactor<3> for Matr3 {
+impl<T: Scalar> Cofactor<3, T> for Matrix3<T> {
     #[inline]
-    fn cofactor(&self, row: usize, col: usize) -> f32 {
+    fn cofactor(&self, row: usize, col: usize) -> T {
         let minor = self.minor(row, col);
 
-        if (row + col) % 2 != 0 {
+        if !(row + col).is_multiple_of(2) {
             -minor
         } else {
             minor
@@ -16,12 +17,12 @@ impl Cofactor<3> for Matr3 {
         // generic_cofactor(self, row, col)
     }
 }
-impl Cofactor<4> for Matr4 {
+impl<T: Scalar> Cofactor<4, T> for Matrix4<T> {
     #[inline]
-    fn cofactor(&self, row: usize, col: usize) -> f32 {
+    fn cofactor(&self, row: usize, col: usize) -> T {
         let minor = self.minor(row, col);
 
-        if (row + col) % 2 != 0 {
+        if !(row + col).is_multiple_of(2) {
             -minor
         } else {
             minor
@@ -1 +1,137 @@
+//! Closed-form determinant and inverse of a [`Matrix4`].
+//!
+//! Both start from the twelve 2x2 determinants of the top two rows (`s0..s5`)
+//! and of the bottom two rows (`c0..c5`), computed in one pass over the
+//! `Simd<T, 16>`. Every element of the adjugate is then a sum of three
+//! products of a matrix element and one of those determinants, which is again
+//! three lane-wise products over the whole matrix.
+//!
+//! Affine transforms skip all of that, see [`Matrix4::inverse_affine`] and
+//! [`Matrix4::inverse_rigid`].
 
+use core::simd::{Simd, simd_swizzle};
+use core::simd::num::SimdFloat;
+
+use crate::matrix::Matrix4;
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::{simd_almost_eq, Cross, Dot, Vertex4};
+
+// Lanes of `sub_determinants`: [s0, s1, s2, s3, s4, s5, c0, c1, c2, c3, c4, c5, 0, 0, 0, 0]
+//
+// s0 = m00*m11 - m10*m01    c0 = m20*m31 - m30*m21
+// s1 = m00*m12 - m10*m02    c1 = m20*m32 - m30*m22
+// s2 = m00*m13 - m10*m03    c2 = m20*m33 - m30*m23
+// s3 = m01*m12 - m11*m02    c3 = m21*m32 - m31*m22
+// s4 = m01*m13 - m11*m03    c4 = m21*m33 - m31*m23
+// s5 = m02*m13 - m12*m03    c5 = m22*m33 - m32*m23
+const SUB_A: [usize; 16] = [0, 0, 0, 1, 1, 2, 8, 8, 8, 9, 9, 10, 0, 0, 0, 0];
+const SUB_B: [usize; 16] = [5, 6, 7, 6, 7, 7, 13, 14, 15, 14, 15, 15, 0, 0, 0, 0];
+const SUB_C: [usize; 16] = [4, 4, 4, 5, 5, 6, 12, 12, 12, 13, 13, 14, 0, 0, 0, 0];
+const SUB_D: [usize; 16] = [1, 2, 3, 2, 3, 3, 9, 10, 11, 10, 11, 11, 0, 0, 0, 0];
+// det = s0*c5 - s1*c4 + s2*c3 + s3*c2 - s4*c1 + s5*c0, lane 12 is zero
+const DET_PAIR: [usize; 16] = [11, 10, 9, 8, 7, 6, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12];
+// adj[(r, c)] = ±(m[M0] * d[D0] - m[M1] * d[D1] + m[M2] * d[D2]), the sign
+// alternating like the cofactors
+const ADJ_M0: [usize; 16] = [5, 1, 13, 9, 4, 0, 12, 8, 4, 0, 12, 8, 4, 0, 12, 8];
+const ADJ_M1: [usize; 16] = [6, 2, 14, 10, 6, 2, 14, 10, 5, 1, 13, 9, 5, 1, 13, 9];
+const ADJ_M2: [usize; 16] = [7, 3, 15, 11, 7, 3, 15, 11, 7, 3, 15, 11, 6, 2, 14, 10];
+const ADJ_D0: [usize; 16] = [11, 11, 5, 5, 11, 11, 5, 5, 10, 10, 4, 4, 9, 9, 3, 3];
+const ADJ_D1: [usize; 16] = [10, 10, 4, 4, 8, 8, 2, 2, 8, 8, 2, 2, 7, 7, 1, 1];
+const ADJ_D2: [usize; 16] = [9, 9, 3, 3, 7, 7, 1, 1, 6, 6, 0, 0, 6, 6, 0, 0];
+
+#[inline]
+fn sub_determinants<T: Scalar>(m: Simd<T, 16>) -> Simd<T, 16>
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    simd_swizzle!(m, SUB_A) * simd_swizzle!(m, SUB_B) - simd_swizzle!(m, SUB_C) * simd_swizzle!(m, SUB_D)
+}
+#[inline]
+fn determinant_from<T: Scalar>(d: Simd<T, 16>) -> T
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    let (o, l) = (T::ZERO, T::ONE);
+    let sign = Simd::from_array([l, -l, l, l, -l, l, o, o, o, o, o, o, o, o, o, o]);
+    (d * simd_swizzle!(d, DET_PAIR) * sign).reduce_sum()
+}
+#[inline]
+pub(crate) fn determinant_4<T: Scalar>(m: &Matrix4<T>) -> T
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    determinant_from(sub_determinants(m.0))
+}
+pub(crate) fn inverse_4<T: Scalar>(m: &Matrix4<T>) -> Option<Matrix4<T>>
+where
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    let m = m.0;
+    let d = sub_determinants(m);
+    let det = determinant_from(d);
+    if det == T::ZERO {
+        return None;
+    }
+    let l = T::ONE;
+    let sign = Simd::from_array([l, -l, l, -l, -l, l, -l, l, l, -l, l, -l, -l, l, -l, l]);
+    let adjugate = simd_swizzle!(m, ADJ_M0) * simd_swizzle!(d, ADJ_D0)
+        - simd_swizzle!(m, ADJ_M1) * simd_swizzle!(d, ADJ_D1)
+        + simd_swizzle!(m, ADJ_M2) * simd_swizzle!(d, ADJ_D2);
+    // Dividing rather than multiplying by `1 / det` keeps integer cofactors exact
+    Some(Matrix4(adjugate * sign / Simd::splat(det)))
+}
+impl<T: Scalar> Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    /// Whether the bottom row is `[0, 0, 0, 1]`
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        let (o, l) = (T::ZERO, T::ONE);
+        simd_almost_eq(&simd_swizzle!(self.0, [12, 13, 14, 15]), &Simd::from_array([o, o, o, l]))
+    }
+    // Columns of the upper-left 3x3 part and the translation, all with `w = 0`
+    #[inline]
+    fn affine_columns(&self) -> [Vertex4<T>; 4] {
+        debug_assert!(self.is_affine(), "bottom row of an affine transform must be [0, 0, 0, 1]");
+        core::array::from_fn(|col| Vertex4::vector(self[(0, col)], self[(1, col)], self[(2, col)]))
+    }
+    /// Inverse of an affine transform, e.g. any product of translations,
+    /// scalings, rotations and shears: the inverse of the 3x3 part and the
+    /// translation mapped back through it. `None` if the 3x3 part is singular.
+    pub fn inverse_affine(&self) -> Option<Matrix4<T>> {
+        let [c0, c1, c2, t] = self.affine_columns();
+        // The rows of the inverse are the cross products of the columns, over the determinant
+        let r0 = Cross::cross(&c1, &c2);
+        let det = Dot::dot(&c0, &r0);
+        if det == T::ZERO {
+            return None;
+        }
+        let rows = [r0 / det, Cross::cross(&c2, &c0) / det, Cross::cross(&c0, &c1) / det];
+        Some(affine_from_rows(rows, &t))
+    }
+    /// Inverse of a rotation followed by a translation: the transpose of the
+    /// 3x3 part and the negated translation rotated back. The 3x3 part must be
+    /// orthonormal, i.e. no scaling or shearing.
+    pub fn inverse_rigid(&self) -> Matrix4<T> {
+        let [c0, c1, c2, t] = self.affine_columns();
+        affine_from_rows([c0, c1, c2], &t)
+    }
+}
+// The affine transform with `rows` as the 3x3 part, undoing the translation `t`
+#[inline]
+fn affine_from_rows<T: Scalar>(rows: [Vertex4<T>; 3], t: &Vertex4<T>) -> Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    let (o, l) = (T::ZERO, T::ONE);
+    let [r0, r1, r2] = rows.map(|row| [row.x(), row.y(), row.z(), -Dot::dot(&row, t)]);
+    #[rustfmt::skip]
+    let array = [
+        r0[0], r0[1], r0[2], r0[3],
+        r1[0], r1[1], r1[2], r1[3],
+        r2[0], r2[1], r2[2], r2[3],
+        o, o, o, l,
+    ];
+    Matrix4(Simd::from_array(array))
+}
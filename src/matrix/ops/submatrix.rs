@@ -1,8 +1,8 @@
 //! Submatrix-ing operation
 
 use crate::{
-    Matr2, Matr3, Matr4,
-    matrix::{AsArray, FromArray},
+    matrix::{AsArray, FromArray, Matrix2, Matrix3, Matrix4},
+    scalar::Scalar,
 };
 pub trait ConstIndex<Idx: ?Sized> {
     /// The returned type after indexing.
@@ -26,17 +26,18 @@ impl<T, const N: usize> ConstIndex<usize> for [T; N] {
 
 #[inline]
 pub fn array_submatrix<
+    T: Scalar,
     const N: usize,
     const NS: usize,
     const N_ROWS: usize,
     const N_COLS: usize,
 >(
-    in_array: [f32; N],
+    in_array: [T; N],
     omitted_row: usize,
     omitted_col: usize,
-) -> [f32; NS] {
+) -> [T; NS] {
     // Make square array
-    let mut out_array = [0.0; NS];
+    let mut out_array = [T::ZERO; NS];
 
     // Make index counter, so as not to overindex, and write correctly
     let mut array_index = 0;
@@ -76,11 +77,11 @@ pub trait Submatrix<const N: usize> {
     type Output;
     fn submatrix(&self, omitted_row: usize, omitted_col: usize) -> Self::Output;
 }
-impl Submatrix<3> for Matr3 {
-    type Output = Matr2;
+impl<T: Scalar> Submatrix<3> for Matrix3<T> {
+    type Output = Matrix2<T>;
     #[inline]
     fn submatrix(&self, omitted_row: usize, omitted_col: usize) -> Self::Output {
-        Matr2::from_array(array_submatrix::<9, 4, 3, 3>(
+        Matrix2::from_array(array_submatrix::<T, 9, 4, 3, 3>(
             *self.as_array(),
             omitted_row,
             omitted_col,
@@ -92,11 +93,11 @@ impl Submatrix<3> for Matr3 {
 // impl ArraySubmatrix<3, 2> for [f32; 9] {}
 // impl ArraySubmatrix<4, 3> for [f32; 16] {}
 
-impl Submatrix<4> for Matr4 {
-    type Output = Matr3;
+impl<T: Scalar> Submatrix<4> for Matrix4<T> {
+    type Output = Matrix3<T>;
     #[inline]
     fn submatrix(&self, omitted_row: usize, omitted_col: usize) -> Self::Output {
-        Matr3::from_array(array_submatrix::<16, 9, 4, 4>(
+        Matrix3::from_array(array_submatrix::<T, 16, 9, 4, 4>(
             *self.as_array(),
             omitted_row,
             omitted_col,
@@ -107,6 +108,7 @@ impl Submatrix<4> for Matr4 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::matrix::{Matr2, Matr3, Matr4};
     mod mat3 {
         use crate::matrix::FromArray;
 
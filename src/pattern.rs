@@ -0,0 +1,204 @@
+//! Procedural colour patterns.
+//!
+//! A pattern is defined in its own pattern space and carries the
+//! [`Transform`] into the object space of the shape it is painted on, the way
+//! a shape carries the one into world space. [`Pattern`] implementations only
+//! handle the pattern space point, the object and world space entry points on
+//! `dyn Pattern` transform into it.
+//!
+//! The two-tone patterns paint with other patterns, so they nest: each
+//! sub-pattern sees the point in its parent's pattern space and applies its
+//! own transform on top. [`Solid`] ends the nesting, and every two-tone
+//! pattern has a `new` taking plain colours.
+
+use core::fmt::Debug;
+
+use crate::color::Color;
+use crate::shapes::Shape;
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+pub trait Pattern: Debug {
+    /// Pattern to object space
+    fn transform(&self) -> &Transform;
+    fn set_transform(&mut self, transform: Transform);
+    #[inline]
+    fn with_transform(mut self, transform: Transform) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+    /// Colour at a point in pattern space
+    fn local_color_at(&self, point: &Vert4) -> Color;
+}
+impl<'p> dyn Pattern + 'p {
+    /// Colour at a point in object space
+    #[inline]
+    pub fn color_at(&self, point: &Vert4) -> Color {
+        self.local_color_at(&(self.transform().inverse() * point))
+    }
+    /// Colour at a world space point on `object`
+    #[inline]
+    pub fn color_at_object(&self, object: &dyn Shape, point: &Vert4) -> Color {
+        self.color_at(&object.world_to_object(point))
+    }
+}
+// Whether the floors of the coordinates add up to an even number
+#[inline]
+fn even(coordinates: &[f32]) -> bool {
+    coordinates.iter().map(|coordinate| coordinate.floor()).sum::<f32>().rem_euclid(2.) == 0.
+}
+/// The same colour everywhere
+#[derive(Debug, Default)]
+pub struct Solid {
+    color: Color,
+    transform: Transform,
+}
+impl Solid {
+    #[inline]
+    pub fn new(color: Color) -> Solid {
+        Solid { color, transform: Transform::identity() }
+    }
+    #[inline]
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+}
+impl Pattern for Solid {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, _point: &Vert4) -> Color {
+        self.color
+    }
+}
+// Declares a pattern alternating between, or mixing, two sub-patterns `a` and
+// `b`, with a `new` painting them in plain colours and a `nested`
+macro_rules! two_tone_pattern {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name {
+            a: Box<dyn Pattern>,
+            b: Box<dyn Pattern>,
+            transform: Transform,
+        }
+        impl $name {
+            #[inline]
+            pub fn new(a: Color, b: Color) -> $name {
+                $name::nested(Solid::new(a), Solid::new(b))
+            }
+            #[inline]
+            pub fn nested<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B) -> $name {
+                $name { a: Box::new(a), b: Box::new(b), transform: Transform::identity() }
+            }
+            #[inline]
+            pub fn a(&self) -> &dyn Pattern {
+                self.a.as_ref()
+            }
+            #[inline]
+            pub fn b(&self) -> &dyn Pattern {
+                self.b.as_ref()
+            }
+        }
+    };
+}
+two_tone_pattern!(
+    /// `a` and `b` in stripes of width 1 along x, `a` from `x = 0`
+    Stripe
+);
+impl Pattern for Stripe {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        if even(&[point.x()]) { self.a.color_at(point) } else { self.b.color_at(point) }
+    }
+}
+two_tone_pattern!(
+    /// From `a` at `x = 0` to `b` towards `x = 1`, repeating
+    Gradient
+);
+impl Pattern for Gradient {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        let a = self.a.color_at(point);
+        let fraction = point.x() - point.x().floor();
+        a + (self.b.color_at(point) - a) * fraction
+    }
+}
+two_tone_pattern!(
+    /// `a` and `b` in concentric rings of width 1 around the y axis, `a` in
+    /// the middle
+    Ring
+);
+impl Pattern for Ring {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        let distance = point.x().hypot(point.z());
+        if even(&[distance]) { self.a.color_at(point) } else { self.b.color_at(point) }
+    }
+}
+two_tone_pattern!(
+    /// `a` and `b` in unit squares over the xz plane, the same at any height.
+    /// Unlike [`Checker3d`], stays clean on surfaces lying in the plane.
+    Checker2d
+);
+impl Pattern for Checker2d {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        if even(&[point.x(), point.z()]) { self.a.color_at(point) } else { self.b.color_at(point) }
+    }
+}
+two_tone_pattern!(
+    /// `a` and `b` in unit cubes, `a` in the one with its corner at the origin
+    Checker3d
+);
+impl Pattern for Checker3d {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        if even(&[point.x(), point.y(), point.z()]) { self.a.color_at(point) } else { self.b.color_at(point) }
+    }
+}
+/// Two sub-patterns mixed, `weight` of the way from `a` to `b`
+#[derive(Debug)]
+pub struct Blend {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    weight: f32,
+    transform: Transform,
+}
+impl Blend {
+    /// Half and half
+    #[inline]
+    pub fn new<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B) -> Blend {
+        Blend::weighted(a, b, 0.5)
+    }
+    #[inline]
+    pub fn weighted<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B, weight: f32) -> Blend {
+        Blend { a: Box::new(a), b: Box::new(b), weight, transform: Transform::identity() }
+    }
+    #[inline]
+    pub fn a(&self) -> &dyn Pattern {
+        self.a.as_ref()
+    }
+    #[inline]
+    pub fn b(&self) -> &dyn Pattern {
+        self.b.as_ref()
+    }
+    #[inline]
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+}
+impl Pattern for Blend {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        self.a.color_at(point) * (1. - self.weight) + self.b.color_at(point) * self.weight
+    }
+}
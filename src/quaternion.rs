@@ -0,0 +1,307 @@
+//! Unit quaternions for rotations.
+//!
+//! Stored as `[x, y, z, w]` in a single `Simd<T, 4>`, `w` being the real part.
+//! Composing with `*` follows the matrix convention: `a * b` rotates by `b`
+//! first, then by `a`.
+
+use core::ops::{Add, AddAssign};
+use core::ops::{Div, DivAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Sub, SubAssign};
+use core::simd::num::SimdFloat;
+use core::simd::{Simd, simd_swizzle};
+
+use crate::matrix::{FromArray, Inverse, Matrix3, Matrix4};
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::{simd_almost_eq, Cross, Dot, Mag, Norm, Vertex4};
+
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Quaternion<T: Scalar>(Simd<T, 4>);
+pub type Quat = Quaternion<f32>;
+pub type DQuat = Quaternion<f64>;
+
+impl<T: Scalar> Quaternion<T> {
+    pub const IDENTITY: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self(Simd::from_array([x, y, z, w]))
+    }
+    #[inline]
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+    #[inline]
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+    #[inline]
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+    #[inline]
+    pub fn w(&self) -> T {
+        self.0[3]
+    }
+}
+impl<T: Scalar> Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    /// Rotation of `rad` about `axis`, which does not need to be normalized.
+    /// Only the `x`, `y` and `z` of `axis` are used.
+    #[inline]
+    pub fn from_axis_angle_rad(axis: &Vertex4<T>, rad: T) -> Quaternion<T> {
+        let half = rad * T::HALF;
+        let axis = Vertex4::vector(axis.x(), axis.y(), axis.z()).norm() * half.sin();
+        Quaternion::new(axis.x(), axis.y(), axis.z(), half.cos())
+    }
+    #[inline]
+    pub fn from_axis_angle_deg(axis: &Vertex4<T>, deg: T) -> Quaternion<T> {
+        Quaternion::from_axis_angle_rad(axis, deg.to_radians())
+    }
+    /// Negates the imaginary part, which is the inverse for unit quaternions
+    #[inline]
+    pub fn conjugate(&self) -> Quaternion<T> {
+        let sign = Simd::from_array([-T::ONE, -T::ONE, -T::ONE, T::ONE]);
+        Quaternion(self.0 * sign)
+    }
+    /// Linear interpolation along the shortest path, renormalized
+    pub fn nlerp(&self, other: &Quaternion<T>, t: T) -> Quaternion<T> {
+        let other = if Dot::dot(self, other) < T::ZERO { -other } else { Quaternion(other.0) };
+        let lerped = self.0 + (other.0 - self.0) * Simd::splat(t);
+        Quaternion(lerped.norm())
+    }
+    /// Spherical interpolation along the shortest path. Falls back to
+    /// [`Quaternion::nlerp`] when the two are nearly the same rotation.
+    pub fn slerp(&self, other: &Quaternion<T>, t: T) -> Quaternion<T> {
+        let (other, cos_theta) = match Dot::dot(self, other) {
+            dot if dot < T::ZERO => (-other, -dot),
+            dot => (Quaternion(other.0), dot),
+        };
+        if cos_theta > T::ONE - T::EPSILON {
+            return self.nlerp(&other, t);
+        }
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let from = ((T::ONE - t) * theta).sin() / sin_theta;
+        let to = (t * theta).sin() / sin_theta;
+        Quaternion(self.0 * Simd::splat(from) + other.0 * Simd::splat(to))
+    }
+}
+impl<T: Scalar> From<Simd<T, 4>> for Quaternion<T> {
+    #[inline]
+    fn from(value: Simd<T, 4>) -> Self {
+        Self(value)
+    }
+}
+impl<T: Scalar> PartialEq for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        simd_almost_eq(&self.0, &other.0)
+    }
+}
+// Operator impls---------
+binop_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Add, add, AddAssign, add_assign, |l, r| l + r);
+binop_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Sub, sub, SubAssign, sub_assign, |l, r| l - r);
+binop_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| hamilton(l, r));
+scalar_op_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| l * Simd::splat(r));
+scalar_op_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Div, div, DivAssign, div_assign, |l, r| l / Simd::splat(r));
+neg_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], |v| -v);
+product_impl!(Quaternion [Simd<T, 4>: SimdOps<T, 4>], Dot, dot, T, |l, r| (l * r).reduce_sum());
+// x = w1x2 + x1w2 + y1z2 - z1y2
+// y = w1y2 + y1w2 + z1x2 - x1z2
+// z = w1z2 + z1w2 + x1y2 - y1x2
+// w = w1w2 - x1x2 - y1y2 - z1z2
+#[inline]
+fn hamilton<T: Scalar>(l: Simd<T, 4>, r: Simd<T, 4>) -> Simd<T, 4>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    let sign = Simd::from_array([T::ONE, T::ONE, T::ONE, -T::ONE]);
+    let w1 = Simd::splat(l[3]);
+    let prod_0 = simd_swizzle!(l, [0, 1, 2, 0]) * simd_swizzle!(r, [3, 3, 3, 0]);
+    let prod_1 = simd_swizzle!(l, [1, 2, 0, 1]) * simd_swizzle!(r, [2, 0, 1, 1]);
+    let prod_2 = simd_swizzle!(l, [2, 0, 1, 2]) * simd_swizzle!(r, [1, 2, 0, 2]);
+    w1 * r + (prod_0 + prod_1) * sign - prod_2
+}
+impl<T: Scalar> Mag for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = T;
+    #[inline]
+    fn mag(&self) -> Self::Output {
+        self.0.mag()
+    }
+}
+impl<T: Scalar> Norm for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Quaternion<T>;
+    #[inline]
+    fn norm(&self) -> Self::Output {
+        Quaternion(self.0.norm())
+    }
+}
+/// `None` for the zero quaternion
+impl<T: Scalar> Inverse for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Inverted = Quaternion<T>;
+    #[inline]
+    fn inverse(&self) -> Option<Self::Inverted> {
+        let mag_sq = Dot::dot(self, self);
+        if mag_sq == T::ZERO {
+            None
+        } else {
+            Some(self.conjugate() / mag_sq)
+        }
+    }
+}
+// Rotating keeps `w`, so points and vectors can both be rotated
+#[inline]
+fn rotate<T: Scalar>(q: &Quaternion<T>, v: &Vertex4<T>) -> Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    // v' = v + w * t + u × t, with t = 2 * (u × v)
+    let u = Vertex4::vector(q.x(), q.y(), q.z());
+    let xyz = Vertex4::vector(v.x(), v.y(), v.z());
+    let t = Cross::cross(&u, &xyz) * T::TWO;
+    let rotated = xyz + t * q.w() + Cross::cross(&u, &t);
+    Vertex4::new(rotated.x(), rotated.y(), rotated.z(), v.w())
+}
+impl<T: Scalar> Mul<Vertex4<T>> for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
+    #[inline]
+    fn mul(self, rhs: Vertex4<T>) -> Self::Output {
+        rotate(&self, &rhs)
+    }
+}
+impl<T: Scalar> Mul<&Vertex4<T>> for Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
+    #[inline]
+    fn mul(self, rhs: &Vertex4<T>) -> Self::Output {
+        rotate(&self, rhs)
+    }
+}
+impl<T: Scalar> Mul<Vertex4<T>> for &Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
+    #[inline]
+    fn mul(self, rhs: Vertex4<T>) -> Self::Output {
+        rotate(self, &rhs)
+    }
+}
+impl<T: Scalar> Mul<&Vertex4<T>> for &Quaternion<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
+    #[inline]
+    fn mul(self, rhs: &Vertex4<T>) -> Self::Output {
+        rotate(self, rhs)
+    }
+}
+// Matrix impls---------
+//
+// Rows of the rotation matrix of a unit quaternion
+#[inline]
+fn rotation_rows<T: Scalar>(q: &Quaternion<T>) -> [[T; 3]; 3] {
+    let [x, y, z, w] = q.0.to_array();
+    let (l, two) = (T::ONE, T::TWO);
+    [
+        [l - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+        [two * (x * y + w * z), l - two * (x * x + z * z), two * (y * z - w * x)],
+        [two * (x * z - w * y), two * (y * z + w * x), l - two * (x * x + y * y)],
+    ]
+}
+// Shepperd's method: divides by the largest of the four candidates for
+// numerical stability
+fn from_rotation<T: Scalar>(m: impl Fn(usize, usize) -> T) -> Quaternion<T> {
+    let quarter = T::HALF * T::HALF;
+    let (m00, m11, m22) = (m(0, 0), m(1, 1), m(2, 2));
+    let trace = m00 + m11 + m22;
+    if trace > T::ZERO {
+        let s = (trace + T::ONE).sqrt() * T::TWO;
+        Quaternion::new((m(2, 1) - m(1, 2)) / s, (m(0, 2) - m(2, 0)) / s, (m(1, 0) - m(0, 1)) / s, s * quarter)
+    } else if m00 > m11 && m00 > m22 {
+        let s = (T::ONE + m00 - m11 - m22).sqrt() * T::TWO;
+        Quaternion::new(s * quarter, (m(0, 1) + m(1, 0)) / s, (m(0, 2) + m(2, 0)) / s, (m(2, 1) - m(1, 2)) / s)
+    } else if m11 > m22 {
+        let s = (T::ONE + m11 - m00 - m22).sqrt() * T::TWO;
+        Quaternion::new((m(0, 1) + m(1, 0)) / s, s * quarter, (m(1, 2) + m(2, 1)) / s, (m(0, 2) - m(2, 0)) / s)
+    } else {
+        let s = (T::ONE + m22 - m00 - m11).sqrt() * T::TWO;
+        Quaternion::new((m(0, 2) + m(2, 0)) / s, (m(1, 2) + m(2, 1)) / s, s * quarter, (m(1, 0) - m(0, 1)) / s)
+    }
+}
+impl<T: Scalar> From<&Quaternion<T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: &Quaternion<T>) -> Self {
+        let [r0, r1, r2] = rotation_rows(value);
+        let (o, l) = (T::ZERO, T::ONE);
+        #[rustfmt::skip]
+        let array = [
+            r0[0], r0[1], r0[2], o,
+            r1[0], r1[1], r1[2], o,
+            r2[0], r2[1], r2[2], o,
+            o, o, o, l,
+        ];
+        Matrix4::from_array(array)
+    }
+}
+impl<T: Scalar> From<Quaternion<T>> for Matrix4<T> {
+    #[inline]
+    fn from(value: Quaternion<T>) -> Self {
+        Matrix4::from(&value)
+    }
+}
+impl<T: Scalar> From<&Quaternion<T>> for Matrix3<T> {
+    #[inline]
+    fn from(value: &Quaternion<T>) -> Self {
+        let [r0, r1, r2] = rotation_rows(value);
+        #[rustfmt::skip]
+        let array = [
+            r0[0], r0[1], r0[2],
+            r1[0], r1[1], r1[2],
+            r2[0], r2[1], r2[2],
+        ];
+        Matrix3::from_array(array)
+    }
+}
+impl<T: Scalar> From<Quaternion<T>> for Matrix3<T> {
+    #[inline]
+    fn from(value: Quaternion<T>) -> Self {
+        Matrix3::from(&value)
+    }
+}
+/// Reads the upper-left 3x3 rotation part, which must be orthonormal.
+/// Translation is ignored.
+impl<T: Scalar> From<&Matrix4<T>> for Quaternion<T> {
+    #[inline]
+    fn from(value: &Matrix4<T>) -> Self {
+        from_rotation(|row, col| value[(row, col)])
+    }
+}
+/// The matrix must be orthonormal
+impl<T: Scalar> From<&Matrix3<T>> for Quaternion<T> {
+    #[inline]
+    fn from(value: &Matrix3<T>) -> Self {
+        from_rotation(|row, col| value[(row, col)])
+    }
+}
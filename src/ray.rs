@@ -0,0 +1,51 @@
+//! Rays from a point along a vector.
+
+use core::simd::Simd;
+
+use crate::matrix::Matrix4;
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::Vertex4;
+
+#[derive(Debug, PartialEq)]
+pub struct Ray<T: Scalar = f32>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    /// A point, `w = 1`
+    pub origin: Vertex4<T>,
+    /// A vector, `w = 0`, not necessarily normalized
+    pub direction: Vertex4<T>,
+}
+/// Why [`Ray::transform`] refused a ray
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InvalidRay {
+    /// The origin's `w` is not `1`
+    OriginNotAPoint,
+    /// The direction's `w` is not `0`
+    DirectionNotAVector,
+}
+impl<T: Scalar> Ray<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    pub const fn new(origin: Vertex4<T>, direction: Vertex4<T>) -> Ray<T> {
+        Ray { origin, direction }
+    }
+    /// The point at distance `t` along the ray, in units of the direction
+    #[inline]
+    pub fn position(&self, t: T) -> Vertex4<T> {
+        self.origin + self.direction * t
+    }
+    /// Both the origin and the direction transformed by `matrix`
+    #[inline]
+    pub fn transform(&self, matrix: &Matrix4<T>) -> Result<Ray<T>, InvalidRay> {
+        if !self.origin.is_point() {
+            return Err(InvalidRay::OriginNotAPoint);
+        }
+        if self.direction.w() != T::ZERO {
+            return Err(InvalidRay::DirectionNotAVector);
+        }
+        Ok(Ray { origin: matrix * self.origin, direction: matrix * self.direction })
+    }
+}
@@ -0,0 +1,141 @@
+//! Scalar types the vertices and matrices are generic over
+
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use core::simd::num::SimdFloat;
+use core::simd::{LaneCount, SupportedLaneCount};
+use core::simd::{Mask, SimdElement};
+
+use core::ops::Neg;
+use core::ops::{Add, AddAssign};
+use core::ops::{Div, DivAssign};
+use core::ops::{Mul, MulAssign};
+use core::ops::{Sub, SubAssign};
+
+/// A floating point lane type, implemented for [`f32`] and [`f64`]
+pub trait Scalar:
+    SimdElement
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const HALF: Self;
+    /// Tolerance of the approximate comparisons, `1e-4` for [`f32`] and
+    /// `1e-9` for [`f64`]
+    const EPSILON: Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn acos(self) -> Self;
+    fn abs(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn to_radians(self) -> Self;
+}
+macro_rules! scalar_impl {
+    ($t:ty, $epsilon:expr) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const HALF: Self = 0.5;
+            const EPSILON: Self = $epsilon;
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+            #[inline]
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+            #[inline]
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+            #[inline]
+            fn tan(self) -> Self {
+                <$t>::tan(self)
+            }
+            #[inline]
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+            #[inline]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+            #[inline]
+            fn to_radians(self) -> Self {
+                <$t>::to_radians(self)
+            }
+        }
+    };
+}
+scalar_impl!(f32, 1e-4);
+scalar_impl!(f64, 1e-9);
+
+/// The lane-wise operations of `Simd<T, N>`.
+///
+/// `core::simd` only implements these per concrete lane type, so generic code
+/// over a [`Scalar`] needs the bound `Simd<T, N>: SimdOps<T, N>`.
+pub trait SimdOps<T: Scalar, const N: usize>:
+    Copy
+    + SimdFloat<Scalar = T>
+    + SimdPartialOrd
+    + SimdPartialEq<Mask = Mask<<T as SimdElement>::Mask, N>>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+}
+impl<T, V, const N: usize> SimdOps<T, N> for V
+where
+    LaneCount<N>: SupportedLaneCount,
+    T: Scalar,
+    V: Copy
+        + SimdFloat<Scalar = T>
+        + SimdPartialOrd
+        + SimdPartialEq<Mask = Mask<<T as SimdElement>::Mask, N>>
+        + Add<Output = V>
+        + Sub<Output = V>
+        + Mul<Output = V>
+        + Div<Output = V>
+        + Neg<Output = V>
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + DivAssign,
+{
+}
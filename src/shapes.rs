@@ -0,0 +1,159 @@
+//! Ray-shape intersections.
+//!
+//! Every shape is defined in its own object space, where it is centred on the
+//! origin, and carries the [`Transform`] into world space. [`Shape`]
+//! implementations only handle the object space ray and point, the world space
+//! entry points on `dyn Shape` transform in and out.
+//!
+//! Shapes nest in [`Group`]s, and then their transform leads into the space of
+//! their group rather than world space. Each shape keeps the [`ParentChain`]
+//! of the groups it is in, which the world space entry points walk too.
+
+use core::fmt::Debug;
+
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::{InvalidRay, Ray};
+use crate::scalar::Scalar;
+use crate::transform::Transform;
+use crate::vertex::{Norm, Vert4};
+
+// The transform, material and parents accessors of a shape keeping them in
+// fields of those names, directly or in the field `$inner`
+macro_rules! shape_common_impl {
+    ($($inner:ident)?) => {
+        #[inline]
+        fn transform(&self) -> &Transform {
+            &self$(.$inner)?.transform
+        }
+        #[inline]
+        fn set_transform(&mut self, transform: Transform) {
+            self$(.$inner)?.transform = transform
+        }
+        #[inline]
+        fn material(&self) -> &Material {
+            &self$(.$inner)?.material
+        }
+        #[inline]
+        fn set_material(&mut self, material: Material) {
+            self$(.$inner)?.material = material
+        }
+        #[inline]
+        fn parents(&self) -> &ParentChain {
+            &self$(.$inner)?.parents
+        }
+        #[inline]
+        fn set_parents(&mut self, parents: ParentChain) {
+            self$(.$inner)?.parents = parents
+        }
+    };
+}
+
+mod cone;
+mod cube;
+mod cylinder;
+mod group;
+mod intersection;
+mod plane;
+mod sphere;
+mod triangle;
+
+pub use cone::Cone;
+pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use group::{Group, ParentChain};
+pub use intersection::{Intersection, Intersections};
+pub use plane::Plane;
+pub use sphere::Sphere;
+pub use triangle::{barycentric, SmoothTriangle, Triangle};
+
+/// Tolerance of the intersection tests
+pub(crate) const EPSILON: f32 = <f32 as Scalar>::EPSILON;
+
+pub trait Shape: Debug {
+    /// Object to parent space, which is world space outside of groups
+    fn transform(&self) -> &Transform;
+    fn set_transform(&mut self, transform: Transform);
+    #[inline]
+    fn with_transform(mut self, transform: Transform) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+    fn material(&self) -> &Material;
+    fn set_material(&mut self, material: Material);
+    #[inline]
+    fn with_material(mut self, material: Material) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_material(material);
+        self
+    }
+    /// The groups the shape is in, innermost first
+    fn parents(&self) -> &ParentChain;
+    /// Set by the [`Group`] the shape is added to
+    fn set_parents(&mut self, parents: ParentChain);
+    /// Intersections with a ray already in object space
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a>;
+    /// Whether a ray already in object space hits the shape at some
+    /// `0 <= t < t_max`, as for shadow rays. Shapes that can stop at the
+    /// first hit found override it.
+    #[inline]
+    fn local_any_hit(&self, ray: &Ray, t_max: f32) -> bool {
+        self.local_intersect(ray).iter().any(|i| 0. <= i.t && i.t < t_max)
+    }
+    /// Normal at a point in object space on the surface, not necessarily
+    /// normalized. `None` for containers like [`Group`], which have no
+    /// surface of their own.
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4>;
+    /// Box around the shape in object space
+    fn bounds(&self) -> Aabb;
+}
+impl<'s> dyn Shape + 's {
+    /// Intersections with a ray in parent space, which is world space outside
+    /// of groups. Fails if the ray's origin is not a point or its direction
+    /// not a vector.
+    #[inline]
+    pub fn intersect(&self, ray: &Ray) -> Result<Intersections<'_>, InvalidRay> {
+        let local_ray = ray.transform(self.transform().inverse())?;
+        Ok(self.local_intersect(&local_ray))
+    }
+    /// Whether a ray in parent space hits the shape at some `0 <= t < t_max`.
+    /// Fails like [`intersect`](#method.intersect).
+    #[inline]
+    pub fn any_hit(&self, ray: &Ray, t_max: f32) -> Result<bool, InvalidRay> {
+        let local_ray = ray.transform(self.transform().inverse())?;
+        Ok(self.local_any_hit(&local_ray, t_max))
+    }
+    /// A world space point in object space, through every group the shape is in
+    #[inline]
+    pub fn world_to_object(&self, point: &Vert4) -> Vert4 {
+        self.transform().inverse() * self.parents().world_to_parent(point)
+    }
+    /// An object space normal in world space, normalized, through every group
+    /// the shape is in
+    pub fn normal_to_world(&self, normal: &Vert4) -> Vert4 {
+        let normal = self.transform().apply_normal(normal).norm();
+        self.parents().iter().fold(normal, |normal, parent| parent.apply_normal(&normal).norm())
+    }
+    /// Normalized world space normal at a world space point on the surface,
+    /// `None` for containers
+    #[inline]
+    pub fn normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        let local_normal = self.local_normal_at(&self.world_to_object(point))?;
+        Some(self.normal_to_world(&local_normal))
+    }
+    /// Box around the shape in parent space
+    #[inline]
+    pub fn parent_bounds(&self) -> Aabb {
+        self.bounds().transform(self.transform().matrix())
+    }
+    /// Box around the shape in world space, through every group it is in
+    #[inline]
+    pub fn world_bounds(&self) -> Aabb {
+        self.parents().iter().fold(self.parent_bounds(), |bounds, parent| bounds.transform(parent.matrix()))
+    }
+}
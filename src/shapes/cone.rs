@@ -0,0 +1,136 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape, EPSILON};
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+/// Double-napped cone around the y axis, tip at the origin and radius `|y|`,
+/// between `minimum` and `maximum` (both exclusive), optionally closed by caps
+#[derive(Debug)]
+pub struct Cone {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+    minimum: f32,
+    maximum: f32,
+    closed: bool,
+}
+impl Cone {
+    /// Infinite and open
+    #[inline]
+    pub fn new() -> Cone {
+        Cone::open(f32::NEG_INFINITY, f32::INFINITY)
+    }
+    #[inline]
+    pub fn open(minimum: f32, maximum: f32) -> Cone {
+        Cone {
+            transform: Transform::identity(),
+            material: Material::default(),
+            parents: ParentChain::default(),
+            minimum,
+            maximum,
+            closed: false,
+        }
+    }
+    #[inline]
+    pub fn capped(minimum: f32, maximum: f32) -> Cone {
+        Cone {
+            transform: Transform::identity(),
+            material: Material::default(),
+            parents: ParentChain::default(),
+            minimum,
+            maximum,
+            closed: true,
+        }
+    }
+    #[inline]
+    pub fn minimum(&self) -> f32 {
+        self.minimum
+    }
+    #[inline]
+    pub fn maximum(&self) -> f32 {
+        self.maximum
+    }
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction.y().abs() < EPSILON {
+            return;
+        }
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y()) / ray.direction.y();
+            if check_cap(ray, t, y.abs()) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+impl Default for Cone {
+    #[inline]
+    fn default() -> Self {
+        Cone::new()
+    }
+}
+// Whether the ray at `t` is within `radius` of the y axis
+#[inline]
+fn check_cap(ray: &Ray, t: f32, radius: f32) -> bool {
+    let x = ray.origin.x() + t * ray.direction.x();
+    let z = ray.origin.z() + t * ray.direction.z();
+    x * x + z * z <= radius * radius + EPSILON
+}
+impl Shape for Cone {
+    shape_common_impl!();
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let (ox, oy, oz) = (ray.origin.x(), ray.origin.y(), ray.origin.z());
+        let (dx, dy, dz) = (ray.direction.x(), ray.direction.y(), ray.direction.z());
+        let a = dx * dx - dy * dy + dz * dz;
+        let b = 2. * ox * dx - 2. * oy * dy + 2. * oz * dz;
+        let c = ox * ox - oy * oy + oz * oz;
+        let mut xs = Vec::with_capacity(2);
+        let mut push_if_in_range = |t: f32| {
+            let y = oy + t * dy;
+            if self.minimum < y && y < self.maximum {
+                xs.push(Intersection::new(t, self));
+            }
+        };
+        if a.abs() < EPSILON {
+            // Parallel to one of the halves: a single hit on the other
+            if b.abs() >= EPSILON {
+                push_if_in_range(-c / (2. * b));
+            }
+        } else {
+            // Rays through the tip graze the surface, round-off must not turn
+            // them into misses
+            let discriminant = b * b - 4. * a * c;
+            if discriminant < -EPSILON {
+                return Intersections::default();
+            }
+            let root = discriminant.max(0.).sqrt();
+            push_if_in_range((-b - root) / (2. * a));
+            push_if_in_range((-b + root) / (2. * a));
+        }
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+        let distance = x * x + z * z;
+        if distance < y * y && y >= self.maximum - EPSILON {
+            Some(Vert4::vector(0., 1., 0.))
+        } else if distance < y * y && y <= self.minimum + EPSILON {
+            Some(Vert4::vector(0., -1., 0.))
+        } else {
+            let y = distance.sqrt();
+            let y = if point.y() > 0. { -y } else { y };
+            Some(Vert4::vector(x, y, z))
+        }
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        let radius = self.minimum.abs().max(self.maximum.abs());
+        Aabb::new(&Vert4::point(-radius, self.minimum, -radius), &Vert4::point(radius, self.maximum, radius))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape, EPSILON};
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+/// Axis-aligned cube spanning `[-1, 1]` on every axis
+#[derive(Debug, Default)]
+pub struct Cube {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+}
+impl Cube {
+    #[inline]
+    pub fn new() -> Cube {
+        Cube::default()
+    }
+}
+// Distances to the two faces of a slab, nearest first
+#[inline]
+fn check_axis(origin: f32, direction: f32) -> (f32, f32) {
+    let tmin_numerator = -1. - origin;
+    let tmax_numerator = 1. - origin;
+    let (tmin, tmax) = if direction.abs() >= EPSILON {
+        (tmin_numerator / direction, tmax_numerator / direction)
+    } else {
+        (tmin_numerator * f32::INFINITY, tmax_numerator * f32::INFINITY)
+    };
+    if tmin > tmax { (tmax, tmin) } else { (tmin, tmax) }
+}
+impl Shape for Cube {
+    shape_common_impl!();
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let (xtmin, xtmax) = check_axis(ray.origin.x(), ray.direction.x());
+        let (ytmin, ytmax) = check_axis(ray.origin.y(), ray.direction.y());
+        let (ztmin, ztmax) = check_axis(ray.origin.z(), ray.direction.z());
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+        if tmin > tmax {
+            return Intersections::default();
+        }
+        Intersections::new(vec![Intersection::new(tmin, self), Intersection::new(tmax, self)])
+    }
+    /// The face normal of the largest component
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+        let max = x.abs().max(y.abs()).max(z.abs());
+        if max == x.abs() {
+            Some(Vert4::vector(x, 0., 0.))
+        } else if max == y.abs() {
+            Some(Vert4::vector(0., y, 0.))
+        } else {
+            Some(Vert4::vector(0., 0., z))
+        }
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        Aabb::new(&Vert4::point(-1., -1., -1.), &Vert4::point(1., 1., 1.))
+    }
+}
@@ -0,0 +1,125 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape, EPSILON};
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+/// Cylinder of radius 1 around the y axis, between `minimum` and `maximum`
+/// (both exclusive), optionally closed by caps
+#[derive(Debug)]
+pub struct Cylinder {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+    minimum: f32,
+    maximum: f32,
+    closed: bool,
+}
+impl Cylinder {
+    /// Infinite and open
+    #[inline]
+    pub fn new() -> Cylinder {
+        Cylinder::open(f32::NEG_INFINITY, f32::INFINITY)
+    }
+    #[inline]
+    pub fn open(minimum: f32, maximum: f32) -> Cylinder {
+        Cylinder {
+            transform: Transform::identity(),
+            material: Material::default(),
+            parents: ParentChain::default(),
+            minimum,
+            maximum,
+            closed: false,
+        }
+    }
+    #[inline]
+    pub fn capped(minimum: f32, maximum: f32) -> Cylinder {
+        Cylinder {
+            transform: Transform::identity(),
+            material: Material::default(),
+            parents: ParentChain::default(),
+            minimum,
+            maximum,
+            closed: true,
+        }
+    }
+    #[inline]
+    pub fn minimum(&self) -> f32 {
+        self.minimum
+    }
+    #[inline]
+    pub fn maximum(&self) -> f32 {
+        self.maximum
+    }
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction.y().abs() < EPSILON {
+            return;
+        }
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y()) / ray.direction.y();
+            if check_cap(ray, t) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+impl Default for Cylinder {
+    #[inline]
+    fn default() -> Self {
+        Cylinder::new()
+    }
+}
+// Whether the ray at `t` is within radius 1 of the y axis
+#[inline]
+fn check_cap(ray: &Ray, t: f32) -> bool {
+    let x = ray.origin.x() + t * ray.direction.x();
+    let z = ray.origin.z() + t * ray.direction.z();
+    x * x + z * z <= 1. + EPSILON
+}
+impl Shape for Cylinder {
+    shape_common_impl!();
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let (ox, oz) = (ray.origin.x(), ray.origin.z());
+        let (dx, dz) = (ray.direction.x(), ray.direction.z());
+        let mut xs = Vec::with_capacity(2);
+        let a = dx * dx + dz * dz;
+        // Parallel to the y axis: only the caps can be hit
+        if a.abs() >= EPSILON {
+            let b = 2. * ox * dx + 2. * oz * dz;
+            let c = ox * ox + oz * oz - 1.;
+            let discriminant = b * b - 4. * a * c;
+            if discriminant < 0. {
+                return Intersections::default();
+            }
+            let root = discriminant.sqrt();
+            for t in [(-b - root) / (2. * a), (-b + root) / (2. * a)] {
+                let y = ray.origin.y() + t * ray.direction.y();
+                if self.minimum < y && y < self.maximum {
+                    xs.push(Intersection::new(t, self));
+                }
+            }
+        }
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+        let distance = x * x + z * z;
+        if distance < 1. && y >= self.maximum - EPSILON {
+            Some(Vert4::vector(0., 1., 0.))
+        } else if distance < 1. && y <= self.minimum + EPSILON {
+            Some(Vert4::vector(0., -1., 0.))
+        } else {
+            Some(Vert4::vector(x, 0., z))
+        }
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        Aabb::new(&Vert4::point(-1., self.minimum, -1.), &Vert4::point(1., self.maximum, 1.))
+    }
+}
@@ -0,0 +1,174 @@
+use core::cell::OnceCell;
+use core::iter;
+use std::rc::Rc;
+
+use crate::aabb::Aabb;
+use crate::bvh::Bvh;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersections, Shape};
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+/// The groups a shape is nested in, as the transforms of each into its own
+/// parent space, innermost first. Empty outside of groups.
+///
+/// Links are shared: a group hands each of its children the chain of its own
+/// transform onto its parents, and hands them a new one when either changes.
+#[derive(Debug, Default, Clone)]
+pub struct ParentChain(Option<Rc<ParentLink>>);
+#[derive(Debug)]
+struct ParentLink {
+    transform: Rc<Transform>,
+    outer: ParentChain,
+}
+impl ParentChain {
+    #[inline]
+    fn link(transform: Rc<Transform>, outer: ParentChain) -> ParentChain {
+        ParentChain(Some(Rc::new(ParentLink { transform, outer })))
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+    /// Number of groups
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.iter().count()
+    }
+    /// Group to parent space transforms, innermost first
+    pub fn iter(&self) -> impl Iterator<Item = &Transform> {
+        iter::successors(self.0.as_deref(), |link| link.outer.0.as_deref()).map(|link| link.transform.as_ref())
+    }
+    /// A world space point in the space of the innermost group, outermost
+    /// group first
+    pub fn world_to_parent(&self, point: &Vert4) -> Vert4 {
+        match &self.0 {
+            Some(link) => link.transform.inverse() * link.outer.world_to_parent(point),
+            None => *point,
+        }
+    }
+}
+/// Shapes sharing a transform. Rays only reach the children whose boxes they
+/// cross, found through a [`Bvh`] over the boxes built on the first ray after
+/// children are added.
+///
+/// A group has no surface of its own: its material is unused and it has no
+/// normals.
+#[derive(Debug, Default)]
+pub struct Group {
+    transform: Rc<Transform>,
+    material: Material,
+    parents: ParentChain,
+    children: Vec<Box<dyn Shape>>,
+    // Of each child in group space, and around all of them
+    child_bounds: Vec<Aabb>,
+    bounds: Aabb,
+    // Over `child_bounds`, reset when a child is added
+    bvh: OnceCell<Bvh>,
+}
+impl Group {
+    /// Empty
+    #[inline]
+    pub fn new() -> Group {
+        Group::default()
+    }
+    pub fn add_child<S: Shape + 'static>(&mut self, mut child: S) {
+        child.set_parents(self.chain());
+        let child_bounds = (&child as &dyn Shape).parent_bounds();
+        self.bounds = self.bounds.merge(&child_bounds);
+        self.child_bounds.push(child_bounds);
+        self.children.push(Box::new(child));
+        self.bvh.take();
+    }
+    #[inline]
+    pub fn with_child<S: Shape + 'static>(mut self, child: S) -> Group {
+        self.add_child(child);
+        self
+    }
+    #[inline]
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+    #[inline]
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| Bvh::build(&self.child_bounds))
+    }
+    // What the children are handed as their parents
+    #[inline]
+    fn chain(&self) -> ParentChain {
+        ParentChain::link(Rc::clone(&self.transform), self.parents.clone())
+    }
+    fn relink_children(&mut self) {
+        let chain = self.chain();
+        for child in &mut self.children {
+            child.set_parents(chain.clone());
+        }
+    }
+}
+// Not `shape_common_impl!`: the transform is shared with the children, and
+// changing it or the parents relinks them
+impl Shape for Group {
+    #[inline]
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+    #[inline]
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = Rc::new(transform);
+        self.relink_children();
+    }
+    #[inline]
+    fn material(&self) -> &Material {
+        &self.material
+    }
+    #[inline]
+    fn set_material(&mut self, material: Material) {
+        self.material = material
+    }
+    #[inline]
+    fn parents(&self) -> &ParentChain {
+        &self.parents
+    }
+    #[inline]
+    fn set_parents(&mut self, parents: ParentChain) {
+        self.parents = parents;
+        self.relink_children();
+    }
+    // The children share the ray of the group, which is only reached through
+    // its own checked `intersect` or `any_hit`. The leaves of the hierarchy
+    // hold a few children, each box is checked again before its child.
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let (t_min, t_max) = (f32::NEG_INFINITY, f32::INFINITY);
+        let mut xs = Vec::new();
+        self.bvh().for_each_candidate(ray, t_min, t_max, |i| {
+            if self.child_bounds[i].intersect_ray(ray, t_min, t_max).is_some() {
+                xs.extend(self.children[i].intersect(ray).unwrap_or_default());
+            }
+        });
+        xs.into_iter().collect()
+    }
+    fn local_any_hit(&self, ray: &Ray, t_max: f32) -> bool {
+        self.bvh().any_hit(ray, t_max, |i, t_max| {
+            self.child_bounds[i].intersect_ray(ray, 0., t_max).is_some()
+                && self.children[i].any_hit(ray, t_max).unwrap_or_default()
+        })
+    }
+    /// Always `None`, normals come from the children hit
+    #[inline]
+    fn local_normal_at(&self, _point: &Vert4) -> Option<Vert4> {
+        None
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.bounds.clone()
+    }
+}
@@ -0,0 +1,80 @@
+use core::ops::Deref;
+
+use crate::shapes::Shape;
+
+/// A ray hitting `object` at distance `t`
+#[derive(Debug, Clone, Copy)]
+pub struct Intersection<'a> {
+    pub t: f32,
+    pub object: &'a dyn Shape,
+    /// Barycentric `(u, v)` of the hit, for triangles
+    pub uv: Option<(f32, f32)>,
+}
+impl<'a> Intersection<'a> {
+    #[inline]
+    pub const fn new(t: f32, object: &'a dyn Shape) -> Intersection<'a> {
+        Intersection { t, object, uv: None }
+    }
+    #[inline]
+    pub const fn with_uv(t: f32, object: &'a dyn Shape, u: f32, v: f32) -> Intersection<'a> {
+        Intersection { t, object, uv: Some((u, v)) }
+    }
+}
+/// Same `t` on the same object
+impl PartialEq for Intersection<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && core::ptr::addr_eq(self.object, other.object)
+    }
+}
+/// Intersections sorted by `t`
+#[derive(Debug, Default, PartialEq)]
+pub struct Intersections<'a>(Vec<Intersection<'a>>);
+impl<'a> Intersections<'a> {
+    #[inline]
+    pub fn new(mut intersections: Vec<Intersection<'a>>) -> Intersections<'a> {
+        intersections.sort_by(|l, r| l.t.total_cmp(&r.t));
+        Intersections(intersections)
+    }
+    /// Merges in `other`, keeping the order
+    pub fn append(&mut self, other: Intersections<'a>) {
+        self.0.extend(other.0);
+        self.0.sort_by(|l, r| l.t.total_cmp(&r.t));
+    }
+    /// The visible intersection: the one with the lowest non-negative `t`
+    #[inline]
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        self.0.iter().find(|i| i.t >= 0.)
+    }
+    #[inline]
+    pub fn into_vec(self) -> Vec<Intersection<'a>> {
+        self.0
+    }
+}
+impl<'a> Deref for Intersections<'a> {
+    type Target = [Intersection<'a>];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<'a> From<Vec<Intersection<'a>>> for Intersections<'a> {
+    #[inline]
+    fn from(value: Vec<Intersection<'a>>) -> Self {
+        Intersections::new(value)
+    }
+}
+impl<'a> FromIterator<Intersection<'a>> for Intersections<'a> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Intersection<'a>>>(iter: I) -> Self {
+        Intersections::new(iter.into_iter().collect())
+    }
+}
+impl<'a> IntoIterator for Intersections<'a> {
+    type Item = Intersection<'a>;
+    type IntoIter = std::vec::IntoIter<Intersection<'a>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape, EPSILON};
+use crate::transform::Transform;
+use crate::vertex::Vert4;
+
+/// The xz plane, facing +y
+#[derive(Debug, Default)]
+pub struct Plane {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+}
+impl Plane {
+    #[inline]
+    pub fn new() -> Plane {
+        Plane::default()
+    }
+}
+impl Shape for Plane {
+    shape_common_impl!();
+    /// Rays parallel to the plane, coplanar ones included, miss it
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        if ray.direction.y().abs() < EPSILON {
+            return Intersections::default();
+        }
+        Intersections::new(vec![Intersection::new(-ray.origin.y() / ray.direction.y(), self)])
+    }
+    #[inline]
+    fn local_normal_at(&self, _point: &Vert4) -> Option<Vert4> {
+        Some(Vert4::vector(0., 1., 0.))
+    }
+    /// Unbounded in x and z
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        Aabb::new(&Vert4::point(f32::NEG_INFINITY, 0., f32::NEG_INFINITY), &Vert4::point(f32::INFINITY, 0., f32::INFINITY))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape};
+use crate::transform::Transform;
+use crate::vertex::{Dot, Vert4};
+
+/// Sphere of radius 1 around the origin
+#[derive(Debug, Default)]
+pub struct Sphere {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+}
+impl Sphere {
+    #[inline]
+    pub fn new() -> Sphere {
+        Sphere::default()
+    }
+}
+impl Shape for Sphere {
+    shape_common_impl!();
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let to_ray = ray.origin - Vert4::point(0., 0., 0.);
+        let a = Dot::dot(&ray.direction, &ray.direction);
+        let b = 2. * Dot::dot(&ray.direction, &to_ray);
+        let c = Dot::dot(&to_ray, &to_ray) - 1.;
+        let discriminant = b * b - 4. * a * c;
+        if discriminant < 0. {
+            return Intersections::default();
+        }
+        let root = discriminant.sqrt();
+        Intersections::new(vec![
+            Intersection::new((-b - root) / (2. * a), self),
+            Intersection::new((-b + root) / (2. * a), self),
+        ])
+    }
+    #[inline]
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        Some(point - Vert4::point(0., 0., 0.))
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        Aabb::new(&Vert4::point(-1., -1., -1.), &Vert4::point(1., 1., 1.))
+    }
+}
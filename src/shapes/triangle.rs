@@ -0,0 +1,174 @@
+use crate::aabb::Aabb;
+use crate::lighting::Material;
+use crate::ray::Ray;
+use crate::shapes::{Intersection, Intersections, ParentChain, Shape, EPSILON};
+use crate::transform::Transform;
+use crate::vertex::{Cross, Dot, Norm, Vert4};
+
+/// Flat triangle, its normal given by the winding `p1 → p2 → p3`
+#[derive(Debug)]
+pub struct Triangle {
+    transform: Transform,
+    material: Material,
+    parents: ParentChain,
+    p1: Vert4,
+    p2: Vert4,
+    p3: Vert4,
+    e1: Vert4,
+    e2: Vert4,
+    normal: Vert4,
+}
+impl Triangle {
+    pub fn new(p1: Vert4, p2: Vert4, p3: Vert4) -> Triangle {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        let normal = Cross::cross(&e2, &e1).norm();
+        Triangle {
+            transform: Transform::identity(),
+            material: Material::default(),
+            parents: ParentChain::default(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal,
+        }
+    }
+    #[inline]
+    pub fn p1(&self) -> &Vert4 {
+        &self.p1
+    }
+    #[inline]
+    pub fn p2(&self) -> &Vert4 {
+        &self.p2
+    }
+    #[inline]
+    pub fn p3(&self) -> &Vert4 {
+        &self.p3
+    }
+    /// `p2 - p1`
+    #[inline]
+    pub fn e1(&self) -> &Vert4 {
+        &self.e1
+    }
+    /// `p3 - p1`
+    #[inline]
+    pub fn e2(&self) -> &Vert4 {
+        &self.e2
+    }
+    #[inline]
+    pub fn normal(&self) -> &Vert4 {
+        &self.normal
+    }
+}
+impl Shape for Triangle {
+    shape_common_impl!();
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        match moller_trumbore(ray, &self.p1, &self.e1, &self.e2) {
+            Some((t, u, v)) => Intersections::new(vec![Intersection::with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+    #[inline]
+    fn local_normal_at(&self, _point: &Vert4) -> Option<Vert4> {
+        Some(self.normal)
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        Aabb::from_points([&self.p1, &self.p2, &self.p3])
+    }
+}
+/// Triangle shading with the normals `n1`, `n2`, `n3` given at its vertices
+/// interpolated across the surface
+#[derive(Debug)]
+pub struct SmoothTriangle {
+    triangle: Triangle,
+    n1: Vert4,
+    n2: Vert4,
+    n3: Vert4,
+}
+impl SmoothTriangle {
+    pub fn new(p1: Vert4, p2: Vert4, p3: Vert4, n1: Vert4, n2: Vert4, n3: Vert4) -> SmoothTriangle {
+        SmoothTriangle { triangle: Triangle::new(p1, p2, p3), n1, n2, n3 }
+    }
+    /// The flat triangle underneath
+    #[inline]
+    pub fn triangle(&self) -> &Triangle {
+        &self.triangle
+    }
+    #[inline]
+    pub fn n1(&self) -> &Vert4 {
+        &self.n1
+    }
+    #[inline]
+    pub fn n2(&self) -> &Vert4 {
+        &self.n2
+    }
+    #[inline]
+    pub fn n3(&self) -> &Vert4 {
+        &self.n3
+    }
+    /// The normal interpolated at the barycentric `(u, v)` of an intersection
+    #[inline]
+    pub fn normal_at_uv(&self, u: f32, v: f32) -> Vert4 {
+        self.n2 * u + self.n3 * v + self.n1 * (1. - u - v)
+    }
+}
+impl Shape for SmoothTriangle {
+    shape_common_impl!(triangle);
+    fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+        let t = &self.triangle;
+        match moller_trumbore(ray, &t.p1, &t.e1, &t.e2) {
+            Some((t, u, v)) => Intersections::new(vec![Intersection::with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+    fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+        let (_, u, v) = barycentric(point, &self.triangle.p1, &self.triangle.p2, &self.triangle.p3);
+        Some(self.normal_at_uv(u, v))
+    }
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.triangle.bounds()
+    }
+}
+// Möller–Trumbore: `(t, u, v)` of the hit with the triangle at `p1` spanned
+// by `e1` and `e2`, if any
+fn moller_trumbore(ray: &Ray, p1: &Vert4, e1: &Vert4, e2: &Vert4) -> Option<(f32, f32, f32)> {
+    let dir_cross_e2 = Cross::cross(&ray.direction, e2);
+    let det = Dot::dot(e1, &dir_cross_e2);
+    // Parallel to the triangle
+    if det.abs() < EPSILON {
+        return None;
+    }
+    let f = 1. / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * Dot::dot(&p1_to_origin, &dir_cross_e2);
+    if !(0. ..=1.).contains(&u) {
+        return None;
+    }
+    let origin_cross_e1 = Cross::cross(&p1_to_origin, e1);
+    let v = f * Dot::dot(&ray.direction, &origin_cross_e1);
+    if v < 0. || u + v > 1. {
+        return None;
+    }
+    Some((f * Dot::dot(e2, &origin_cross_e1), u, v))
+}
+/// Barycentric weights `(w, u, v)` of `p` relative to `a`, `b` and `c`, so that
+/// `p = w·a + u·b + v·c` once projected onto the triangle's plane. `u` and
+/// `v` match the ones of an intersection.
+pub fn barycentric(p: &Vert4, a: &Vert4, b: &Vert4, c: &Vert4) -> (f32, f32, f32) {
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = p - a;
+    let d00 = Dot::dot(&v0, &v0);
+    let d01 = Dot::dot(&v0, &v1);
+    let d11 = Dot::dot(&v1, &v1);
+    let d20 = Dot::dot(&v2, &v0);
+    let d21 = Dot::dot(&v2, &v1);
+    let denom = d00 * d11 - d01 * d01;
+    let u = (d11 * d20 - d01 * d21) / denom;
+    let v = (d00 * d21 - d01 * d20) / denom;
+    (1. - u - v, u, v)
+}
@@ -0,0 +1,251 @@
+//! Transforms with their inverse and inverse transpose.
+//!
+//! Transforming a ray needs `M⁻¹`, transforming a normal needs `(M⁻¹)ᵀ`, so
+//! [`Transform`] keeps both next to `M`. Composing updates them with the
+//! product of the inverses instead of inverting again.
+//!
+//! Multiplying matrices applies the rightmost one first, so a chain has to be
+//! written backwards (`c * b * a * p`). The builder methods of [`Transform`]
+//! read in application order instead:
+//!
+//! ```
+//! use geometry::{Matr4, Vert4};
+//! use geometry::transform::Transform;
+//!
+//! let transform: Matr4 = Transform::identity()
+//!     .rotate_x(core::f32::consts::FRAC_PI_2)
+//!     .scale(5., 5., 5.)
+//!     .translate(10., 5., 7.)
+//!     .into();
+//! assert_eq!(transform * Vert4::point(1., 0., 1.), Vert4::point(15., 0., 7.));
+//! ```
+
+use core::ops::Mul;
+use core::simd::Simd;
+
+use crate::matrix::{Inverse, Matrix, Matrix4, ShearingProportion, X, Y, Z};
+use crate::scalar::{Scalar, SimdOps};
+use crate::vertex::Vertex4;
+
+#[derive(Debug, PartialEq)]
+pub struct Transform<T: Scalar = f32>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    matrix: Matrix4<T>,
+    inverse: Matrix4<T>,
+    inverse_transpose: Matrix4<T>,
+}
+impl<T: Scalar> Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    pub fn identity() -> Transform<T> {
+        Transform {
+            matrix: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            inverse_transpose: Matrix4::identity(),
+        }
+    }
+    // For the builders, whose inverse is known up front
+    #[inline]
+    fn with_inverse(matrix: Matrix4<T>, inverse: Matrix4<T>) -> Transform<T> {
+        let inverse_transpose = inverse.transpose();
+        Transform { matrix, inverse, inverse_transpose }
+    }
+    /// Applies `next` after everything so far
+    #[inline]
+    pub fn then(self, next: Transform<T>) -> Transform<T> {
+        next * self
+    }
+    #[inline]
+    pub fn rotate_x(self, rad: T) -> Transform<T> {
+        self.then(Transform::with_inverse(Matrix4::rotation_x_rad(rad), Matrix4::rotation_x_rad(-rad)))
+    }
+    #[inline]
+    pub fn rotate_y(self, rad: T) -> Transform<T> {
+        self.then(Transform::with_inverse(Matrix4::rotation_y_rad(rad), Matrix4::rotation_y_rad(-rad)))
+    }
+    #[inline]
+    pub fn rotate_z(self, rad: T) -> Transform<T> {
+        self.then(Transform::with_inverse(Matrix4::rotation_z_rad(rad), Matrix4::rotation_z_rad(-rad)))
+    }
+    #[inline]
+    pub fn rotate_axis(self, axis: Vertex4<T>, rad: T) -> Transform<T> {
+        let rotation = Matrix4::rotation_axis_angle(axis, rad);
+        let inverse = rotation.transpose();
+        self.then(Transform::with_inverse(rotation, inverse))
+    }
+    /// # Panics
+    ///
+    /// If any of the factors is zero, see [`Transform::try_scale`]
+    #[inline]
+    pub fn scale(self, sx: T, sy: T, sz: T) -> Transform<T> {
+        self.try_scale(sx, sy, sz).expect("cannot scale by zero")
+    }
+    /// Fails, handing back the transform so far, if any of the factors is
+    /// zero
+    #[inline]
+    pub fn try_scale(self, sx: T, sy: T, sz: T) -> Result<Transform<T>, Transform<T>> {
+        if sx == T::ZERO || sy == T::ZERO || sz == T::ZERO {
+            return Err(self);
+        }
+        let l = T::ONE;
+        Ok(self.then(Transform::with_inverse(Matrix4::scaling(sx, sy, sz), Matrix4::scaling(l / sx, l / sy, l / sz))))
+    }
+    #[inline]
+    pub fn translate(self, tx: T, ty: T, tz: T) -> Transform<T> {
+        self.then(Transform::with_inverse(Matrix4::translation(tx, ty, tz), Matrix4::translation(-tx, -ty, -tz)))
+    }
+    /// # Panics
+    ///
+    /// If the shear is not invertible, see [`Transform::try_shear`]
+    #[inline]
+    pub fn shear(
+        self,
+        x: ShearingProportion<X, T>,
+        y: ShearingProportion<Y, T>,
+        z: ShearingProportion<Z, T>,
+    ) -> Transform<T> {
+        self.try_shear(x, y, z).expect("shearing must be invertible")
+    }
+    /// Fails, handing back the transform so far, if the shear is not
+    /// invertible
+    #[inline]
+    pub fn try_shear(
+        self,
+        x: ShearingProportion<X, T>,
+        y: ShearingProportion<Y, T>,
+        z: ShearingProportion<Z, T>,
+    ) -> Result<Transform<T>, Transform<T>> {
+        let shearing = Matrix4::shearing(x, y, z);
+        match shearing.inverse_affine() {
+            Some(inverse) => Ok(self.then(Transform::with_inverse(shearing, inverse))),
+            None => Err(self),
+        }
+    }
+    /// The view transform of an eye at `from` looking at `to`, see
+    /// [`Matrix4::view_transform`]
+    #[inline]
+    pub fn view(self, from: Vertex4<T>, to: Vertex4<T>, up: Vertex4<T>) -> Transform<T> {
+        let view = Matrix4::view_transform(from, to, up);
+        let inverse = view.inverse_rigid();
+        self.then(Transform::with_inverse(view, inverse))
+    }
+    #[inline]
+    pub fn matrix(&self) -> &Matrix4<T> {
+        &self.matrix
+    }
+    #[inline]
+    pub fn inverse(&self) -> &Matrix4<T> {
+        &self.inverse
+    }
+    #[inline]
+    pub fn inverse_transpose(&self) -> &Matrix4<T> {
+        &self.inverse_transpose
+    }
+    #[inline]
+    pub fn apply_point(&self, point: &Vertex4<T>) -> Vertex4<T> {
+        &self.matrix * point
+    }
+    #[inline]
+    pub fn apply_vector(&self, vector: &Vertex4<T>) -> Vertex4<T> {
+        &self.matrix * vector
+    }
+    /// Transforms a surface normal by the inverse transpose, keeping it
+    /// perpendicular to the transformed surface. `w` is forced back to `0`,
+    /// the result is not normalized.
+    #[inline]
+    pub fn apply_normal(&self, normal: &Vertex4<T>) -> Vertex4<T> {
+        let normal = &self.inverse_transpose * normal;
+        Vertex4::vector(normal.x(), normal.y(), normal.z())
+    }
+}
+impl<T: Scalar> Default for Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+/// Fails, handing back the matrix, if it is not invertible
+impl<T: Scalar> TryFrom<Matrix4<T>> for Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+    Simd<T, 16>: SimdOps<T, 16>,
+{
+    type Error = Matrix4<T>;
+    #[inline]
+    fn try_from(value: Matrix4<T>) -> Result<Self, Self::Error> {
+        match value.inverse() {
+            Some(inverse) => Ok(Transform::with_inverse(value, inverse)),
+            None => Err(value),
+        }
+    }
+}
+impl<T: Scalar> From<Transform<T>> for Matrix4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    #[inline]
+    fn from(value: Transform<T>) -> Self {
+        value.matrix
+    }
+}
+// Operator impls---------
+//
+// (AB)⁻¹ = B⁻¹A⁻¹ and ((AB)⁻¹)ᵀ = (A⁻¹)ᵀ(B⁻¹)ᵀ
+#[inline]
+fn compose<T: Scalar>(lhs: &Transform<T>, rhs: &Transform<T>) -> Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    Transform {
+        matrix: &lhs.matrix * &rhs.matrix,
+        inverse: &rhs.inverse * &lhs.inverse,
+        inverse_transpose: &lhs.inverse_transpose * &rhs.inverse_transpose,
+    }
+}
+impl<T: Scalar> Mul<Transform<T>> for Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Transform<T>;
+    #[inline]
+    fn mul(self, rhs: Transform<T>) -> Self::Output {
+        compose(&self, &rhs)
+    }
+}
+impl<T: Scalar> Mul<&Transform<T>> for Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Transform<T>;
+    #[inline]
+    fn mul(self, rhs: &Transform<T>) -> Self::Output {
+        compose(&self, rhs)
+    }
+}
+impl<T: Scalar> Mul<Transform<T>> for &Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Transform<T>;
+    #[inline]
+    fn mul(self, rhs: Transform<T>) -> Self::Output {
+        compose(self, &rhs)
+    }
+}
+impl<T: Scalar> Mul<&Transform<T>> for &Transform<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Transform<T>;
+    #[inline]
+    fn mul(self, rhs: &Transform<T>) -> Self::Output {
+        compose(self, rhs)
+    }
+}
@@ -0,0 +1,240 @@
+//! Texture mapping: surface points to `(u, v)` coordinates, and patterns over
+//! them.
+//!
+//! `u` runs left to right and `v` bottom to top, both over `[0, 1)`. A
+//! [`UvMapping`] takes a point in pattern space to `(u, v)`, a [`UvPattern`]
+//! colours `(u, v)`, and [`TextureMap`] pairs them into a [`Pattern`].
+
+use core::f32::consts::PI;
+use core::fmt::Debug;
+
+use crate::canvas::Canvas;
+use crate::color::Color;
+use crate::pattern::Pattern;
+use crate::transform::Transform;
+use crate::vertex::{Mag, Vert4};
+
+/// How points on a surface unwrap onto the `(u, v)` square
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UvMapping {
+    /// Longitude and latitude around the origin, for spheres. `u` goes round
+    /// the y axis, counterclockwise seen from above, starting at `-z`; `v`
+    /// goes from the south pole to the north pole.
+    Spherical,
+    /// The xz plane tiled with unit squares, for planes
+    Planar,
+    /// Round the y axis like [`UvMapping::Spherical`], and up it in unit
+    /// bands, for cylinders
+    Cylindrical,
+    /// The faces of the cube from `-1` to `1` laid out as a cross, for cubes:
+    /// left, front, right and back in the middle row, up above and down
+    /// below the front
+    Cube,
+}
+impl UvMapping {
+    pub fn map(&self, point: &Vert4) -> (f32, f32) {
+        match self {
+            UvMapping::Spherical => spherical_map(point),
+            UvMapping::Planar => planar_map(point),
+            UvMapping::Cylindrical => cylindrical_map(point),
+            UvMapping::Cube => cube_map(point),
+        }
+    }
+}
+// `u` of the angle round the y axis, wrapped into `[0, 1)` since `atan2`
+// gives `-π` at `x = -0` behind the axis
+#[inline]
+fn azimuth(point: &Vert4) -> f32 {
+    let theta = point.x().atan2(point.z());
+    (1. - (theta / (2. * PI) + 0.5)).rem_euclid(1.)
+}
+/// See [`UvMapping::Spherical`], `point` not at the origin
+pub fn spherical_map(point: &Vert4) -> (f32, f32) {
+    let radius = Vert4::vector(point.x(), point.y(), point.z()).mag();
+    let phi = (point.y() / radius).acos();
+    (azimuth(point), 1. - phi / PI)
+}
+/// See [`UvMapping::Planar`]
+#[inline]
+pub fn planar_map(point: &Vert4) -> (f32, f32) {
+    (point.x().rem_euclid(1.), point.z().rem_euclid(1.))
+}
+/// See [`UvMapping::Cylindrical`]
+#[inline]
+pub fn cylindrical_map(point: &Vert4) -> (f32, f32) {
+    (azimuth(point), point.y().rem_euclid(1.))
+}
+/// A face of the cube from `-1` to `1`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CubeFace {
+    /// `-x`
+    Left,
+    /// `+x`
+    Right,
+    /// `+y`
+    Up,
+    /// `-y`
+    Down,
+    /// `+z`
+    Front,
+    /// `-z`
+    Back,
+}
+/// The face `point` is on, the one its largest coordinate points to
+pub fn cube_face(point: &Vert4) -> CubeFace {
+    let (x, y, z) = (point.x(), point.y(), point.z());
+    let coord = x.abs().max(y.abs()).max(z.abs());
+    if coord == x {
+        CubeFace::Right
+    } else if coord == -x {
+        CubeFace::Left
+    } else if coord == y {
+        CubeFace::Up
+    } else if coord == -y {
+        CubeFace::Down
+    } else if coord == z {
+        CubeFace::Front
+    } else {
+        CubeFace::Back
+    }
+}
+/// `(u, v)` of `point` on its own `face`, seen from outside the cube, up
+/// towards `+y` on the sides, `-z` on top and `+z` below
+pub fn cube_face_uv(face: CubeFace, point: &Vert4) -> (f32, f32) {
+    let (x, y, z) = (point.x(), point.y(), point.z());
+    let half = |coord: f32| coord.rem_euclid(2.) / 2.;
+    match face {
+        CubeFace::Left => (half(z + 1.), half(y + 1.)),
+        CubeFace::Right => (half(1. - z), half(y + 1.)),
+        CubeFace::Up => (half(x + 1.), half(1. - z)),
+        CubeFace::Down => (half(x + 1.), half(z + 1.)),
+        CubeFace::Front => (half(x + 1.), half(y + 1.)),
+        CubeFace::Back => (half(1. - x), half(y + 1.)),
+    }
+}
+/// See [`UvMapping::Cube`]
+pub fn cube_map(point: &Vert4) -> (f32, f32) {
+    let face = cube_face(point);
+    let (u, v) = cube_face_uv(face, point);
+    // Cell of the 4x3 cross, from the bottom left
+    let (column, row) = match face {
+        CubeFace::Left => (0., 1.),
+        CubeFace::Front => (1., 1.),
+        CubeFace::Right => (2., 1.),
+        CubeFace::Back => (3., 1.),
+        CubeFace::Up => (1., 2.),
+        CubeFace::Down => (1., 0.),
+    };
+    ((column + u) / 4., (row + v) / 3.)
+}
+pub trait UvPattern: Debug {
+    fn uv_color_at(&self, u: f32, v: f32) -> Color;
+}
+/// `a` and `b` in a `width` by `height` grid of squares, `a` in the bottom
+/// left
+#[derive(Debug)]
+pub struct UvCheckers {
+    width: f32,
+    height: f32,
+    a: Color,
+    b: Color,
+}
+impl UvCheckers {
+    #[inline]
+    pub const fn new(width: f32, height: f32, a: Color, b: Color) -> UvCheckers {
+        UvCheckers { width, height, a, b }
+    }
+}
+impl UvPattern for UvCheckers {
+    fn uv_color_at(&self, u: f32, v: f32) -> Color {
+        let squares = (u * self.width).floor() + (v * self.height).floor();
+        let color = if squares.rem_euclid(2.) == 0. { &self.a } else { &self.b };
+        *color
+    }
+}
+/// How an [`ImageTexture`] looks up colours between pixel centres
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Filter {
+    /// The nearest pixel
+    #[default]
+    Nearest,
+    /// Interpolated between the four nearest pixels
+    Bilinear,
+}
+/// An image stretched over the `(u, v)` square, its top row at `v = 1`.
+/// Lookups outside the square clamp to the edge.
+#[derive(Debug)]
+pub struct ImageTexture {
+    canvas: Canvas,
+    filter: Filter,
+}
+impl ImageTexture {
+    /// # Panics
+    ///
+    /// If the canvas has no pixels
+    #[inline]
+    pub fn new(canvas: Canvas, filter: Filter) -> ImageTexture {
+        assert!(canvas.width() > 0 && canvas.height() > 0, "cannot texture with an empty canvas");
+        ImageTexture { canvas, filter }
+    }
+    #[inline]
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+    #[inline]
+    pub fn filter(&self) -> Filter {
+        self.filter
+    }
+    // Pixel coordinates, with the corner pixels centred on the corners of the
+    // square
+    #[inline]
+    fn pixel_coordinates(&self, u: f32, v: f32) -> (f32, f32) {
+        let x = u.clamp(0., 1.) * (self.canvas.width() - 1) as f32;
+        let y = (1. - v.clamp(0., 1.)) * (self.canvas.height() - 1) as f32;
+        (x, y)
+    }
+}
+impl UvPattern for ImageTexture {
+    fn uv_color_at(&self, u: f32, v: f32) -> Color {
+        let (x, y) = self.pixel_coordinates(u, v);
+        match self.filter {
+            Filter::Nearest => *self.canvas.pixel_at((x + 0.5) as usize, (y + 0.5) as usize),
+            Filter::Bilinear => {
+                let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+                let (x1, y1) = ((x0 + 1).min(self.canvas.width() - 1), (y0 + 1).min(self.canvas.height() - 1));
+                let (fx, fy) = (x - x0 as f32, y - y0 as f32);
+                let row = |y| self.canvas.pixel_at(x0, y) * (1. - fx) + self.canvas.pixel_at(x1, y) * fx;
+                row(y0) * (1. - fy) + row(y1) * fy
+            }
+        }
+    }
+}
+/// A [`UvPattern`] wrapped round a surface by a [`UvMapping`]
+#[derive(Debug)]
+pub struct TextureMap {
+    uv_pattern: Box<dyn UvPattern>,
+    mapping: UvMapping,
+    transform: Transform,
+}
+impl TextureMap {
+    #[inline]
+    pub fn new<P: UvPattern + 'static>(uv_pattern: P, mapping: UvMapping) -> TextureMap {
+        TextureMap { uv_pattern: Box::new(uv_pattern), mapping, transform: Transform::identity() }
+    }
+    #[inline]
+    pub fn uv_pattern(&self) -> &dyn UvPattern {
+        self.uv_pattern.as_ref()
+    }
+    #[inline]
+    pub fn mapping(&self) -> UvMapping {
+        self.mapping
+    }
+}
+impl Pattern for TextureMap {
+    pattern_transform_impl!();
+    #[inline]
+    fn local_color_at(&self, point: &Vert4) -> Color {
+        let (u, v) = self.mapping.map(point);
+        self.uv_pattern.uv_color_at(u, v)
+    }
+}
@@ -1,449 +1,274 @@
 use core::fmt::{Debug, Formatter, Result as FmtResult};
 
+use core::simd::cmp::{SimdPartialEq, SimdPartialOrd};
 use core::simd::num::SimdFloat;
 use core::simd::{LaneCount, SupportedLaneCount};
-use core::simd::{Simd, f32x2, f32x4, simd_swizzle};
+use core::simd::{Simd, simd_swizzle};
 
 use core::ops::{Deref, DerefMut};
 
-use core::ops::Neg;
 use core::ops::{Add, AddAssign};
 use core::ops::{Div, DivAssign};
 use core::ops::{Mul, MulAssign};
 use core::ops::{Sub, SubAssign};
 
-const SIMD_2_ZERO: Simd<f32, 2> = Simd::from_array([0.0_f32, 0.0_f32]);
-const SIMD_2_X: Simd<f32, 2> = Simd::from_array([1.0_f32, 0.0_f32]);
-const SIMD_2_Y: Simd<f32, 2> = Simd::from_array([0.0_f32, 1.0_f32]);
+use crate::scalar::{Scalar, SimdOps};
 
-pub const fn float_almost_eq(lhs: &f32, rhs: &f32) -> bool {
-    (f32::max(*lhs, *rhs) - f32::min(*lhs, *rhs)) < 1e-4
+pub fn float_almost_eq<T: Scalar>(lhs: &T, rhs: &T) -> bool {
+    (Scalar::max(*lhs, *rhs) - Scalar::min(*lhs, *rhs)) < T::EPSILON
 }
-pub fn float_array_almost_eq<const N: usize>(lhs: &[f32; N], rhs: &[f32; N]) -> bool {
+pub fn float_array_almost_eq<T: Scalar, const N: usize>(lhs: &[T; N], rhs: &[T; N]) -> bool {
     lhs.iter()
         .zip(rhs)
         .all(|(lhs, rhs)| float_almost_eq(lhs, rhs))
 }
-pub fn simd_almost_eq<const N: usize>(lhs: &Simd<f32, N>, rhs: &Simd<f32, N>) -> bool
+/// Lane-wise approximate equality, every lane must be within [`Scalar::EPSILON`]
+/// or equal, which lets infinite lanes compare
+pub fn simd_almost_eq<T: Scalar, const N: usize>(lhs: &Simd<T, N>, rhs: &Simd<T, N>) -> bool
 where
     LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: SimdOps<T, N>,
 {
-    Simd::lt(&SimdFloat::abs(lhs - rhs), &Simd::splat(1e-4))
+    (lhs.simd_eq(*rhs) | SimdFloat::abs(*lhs - *rhs).simd_lt(Simd::splat(T::EPSILON))).all()
 }
 
 #[repr(transparent)]
 #[derive(Debug)]
-pub struct Vert2(f32x2);
+pub struct Vertex2<T: Scalar>(Simd<T, 2>);
+pub type Vert2 = Vertex2<f32>;
+pub type DVert2 = Vertex2<f64>;
 
-impl Deref for Vert2 {
-    type Target = [f32];
+impl<T: Scalar> Deref for Vertex2<T> {
+    type Target = [T];
     fn deref(&self) -> &Self::Target {
         self.0.as_array().as_ref()
     }
 }
-impl Vert2 {
-    pub const ZERO: Self = Self(SIMD_2_ZERO);
-    pub const X: Self = Self(SIMD_2_X);
-    pub const Y: Self = Self(SIMD_2_Y);
+impl<T: Scalar> DerefMut for Vertex2<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut_array().as_mut()
+    }
+}
+impl<T: Scalar> PartialEq for Vertex2<T>
+where
+    Simd<T, 2>: SimdOps<T, 2>,
+{
     #[inline]
-    pub const fn new(x: f32, y: f32) -> Self {
-        Self(f32x2::from_array([x, y]))
+    fn eq(&self, other: &Self) -> bool {
+        simd_almost_eq(&self.0, &other.0)
     }
 }
-impl From<f32x2> for Vert2 {
+impl<T: Scalar> Vertex2<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const X: Self = Self::new(T::ONE, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE);
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        Self(Simd::from_array([x, y]))
+    }
     #[inline]
-    fn from(value: f32x2) -> Self {
+    pub const fn x(&self) -> T {
+        self.0.as_array()[0]
+    }
+    #[inline]
+    pub const fn y(&self) -> T {
+        self.0.as_array()[1]
+    }
+}
+impl<T: Scalar> From<Simd<T, 2>> for Vertex2<T> {
+    #[inline]
+    fn from(value: Simd<T, 2>) -> Self {
         Self(value)
     }
 }
-impl From<&f32x2> for Vert2 {
+impl<T: Scalar> From<&Simd<T, 2>> for Vertex2<T> {
     #[inline]
-    fn from(value: &f32x2) -> Self {
+    fn from(value: &Simd<T, 2>) -> Self {
         Self(*value)
     }
 }
 
-const SIMD_3_ZERO: [f32; 3] = [0.0_f32, 0.0_f32, 0.0_f32];
-const SIMD_3_X: [f32; 3] = [1.0_f32, 0.0_f32, 0.0_f32];
-const SIMD_3_Y: [f32; 3] = [0.0_f32, 1.0_f32, 0.0_f32];
-const SIMD_3_Z: [f32; 3] = [0.0_f32, 0.0_f32, 1.0_f32];
-
 #[repr(transparent)]
-pub struct Vert3([f32; 3]);
+pub struct Vertex3<T: Scalar>([T; 3]);
+pub type Vert3 = Vertex3<f32>;
+pub type DVert3 = Vertex3<f64>;
 
-impl Deref for Vert3 {
-    type Target = [f32];
+impl<T: Scalar> Deref for Vertex3<T> {
+    type Target = [T];
     fn deref(&self) -> &Self::Target {
         self.0.as_ref()
     }
 }
-impl Debug for Vert3 {
+impl<T: Scalar> DerefMut for Vertex3<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut()
+    }
+}
+impl<T: Scalar> Debug for Vertex3<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_tuple("Vert3").field(&self.0).finish()
+        f.debug_tuple("Vertex3").field(&self.0).finish()
     }
 }
-impl PartialEq for Vert3 {
+impl<T: Scalar> PartialEq for Vertex3<T> {
     fn eq(&self, other: &Self) -> bool {
         float_array_almost_eq(&self.0, &other.0)
     }
 }
-impl Vert3 {
-    pub const ZERO: Self = Self(SIMD_3_ZERO);
-    pub const X: Self = Self(SIMD_3_X);
-    pub const Y: Self = Self(SIMD_3_Y);
-    pub const Z: Self = Self(SIMD_3_Z);
+impl<T: Scalar> Vertex3<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const X: Self = Self::new(T::ONE, T::ZERO, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO);
+    pub const Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE);
     #[inline]
-    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self([x, y, z])
     }
+    #[inline]
+    pub const fn x(&self) -> T {
+        self.0[0]
+    }
+    #[inline]
+    pub const fn y(&self) -> T {
+        self.0[1]
+    }
+    #[inline]
+    pub const fn z(&self) -> T {
+        self.0[2]
+    }
 }
-impl From<[f32; 3]> for Vert3 {
+impl<T: Scalar> From<[T; 3]> for Vertex3<T> {
     #[inline]
-    fn from(value: [f32; 3]) -> Self {
+    fn from(value: [T; 3]) -> Self {
         Self(value)
     }
 }
-impl From<&[f32; 3]> for Vert3 {
+impl<T: Scalar> From<&[T; 3]> for Vertex3<T> {
     #[inline]
-    fn from(value: &[f32; 3]) -> Self {
+    fn from(value: &[T; 3]) -> Self {
         Self(*value)
     }
 }
 
-const SIMD_4_ZERO: Simd<f32, 4> = Simd::from_array([0.0_f32, 0.0_f32, 0.0_f32, 0.0_f32]);
-const SIMD_4_X: Simd<f32, 4> = Simd::from_array([1.0_f32, 0.0_f32, 0.0_f32, 0.0_f32]);
-const SIMD_4_Y: Simd<f32, 4> = Simd::from_array([0.0_f32, 1.0_f32, 0.0_f32, 0.0_f32]);
-const SIMD_4_Z: Simd<f32, 4> = Simd::from_array([0.0_f32, 0.0_f32, 1.0_f32, 0.0_f32]);
-const SIMD_4_W: Simd<f32, 4> = Simd::from_array([0.0_f32, 0.0_f32, 0.0_f32, 1.0_f32]);
-
 #[repr(transparent)]
-#[derive(Debug)]
-pub struct Vert4(f32x4);
+#[derive(Debug, Clone, Copy)]
+pub struct Vertex4<T: Scalar>(Simd<T, 4>);
+pub type Vert4 = Vertex4<f32>;
+pub type DVert4 = Vertex4<f64>;
 
-impl Deref for Vert4 {
-    type Target = [f32];
+impl<T: Scalar> Deref for Vertex4<T> {
+    type Target = [T];
     fn deref(&self) -> &Self::Target {
         self.0.as_array().as_ref()
     }
 }
-impl DerefMut for Vert4 {
+impl<T: Scalar> DerefMut for Vertex4<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.0.as_mut_array().as_mut()
     }
 }
-impl Vert4 {
-    pub const ZERO: Self = Self(SIMD_4_ZERO);
-    pub const X: Self = Self(SIMD_4_X);
-    pub const Y: Self = Self(SIMD_4_Y);
-    pub const Z: Self = Self(SIMD_4_Z);
-    pub const W: Self = Self(SIMD_4_W);
+impl<T: Scalar> Vertex4<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    pub const X: Self = Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO, T::ZERO);
+    pub const Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE, T::ZERO);
+    pub const W: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
     #[inline]
-    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Self(f32x4::from_array([x, y, z, w]))
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self(Simd::from_array([x, y, z, w]))
     }
     #[inline]
-    pub fn x(&self) -> f32 {
+    pub fn x(&self) -> T {
         self.0[0]
     }
     #[inline]
-    pub fn y(&self) -> f32 {
+    pub fn y(&self) -> T {
         self.0[1]
     }
     #[inline]
-    pub fn z(&self) -> f32 {
+    pub fn z(&self) -> T {
         self.0[2]
     }
     #[inline]
-    pub const fn w(&self) -> f32 {
+    pub const fn w(&self) -> T {
         self.0.as_array()[3]
     }
     #[inline]
-    pub const fn point(x: f32, y: f32, z: f32) -> Vert4 {
-        Vert4::new(x, y, z, 1.0)
+    pub const fn point(x: T, y: T, z: T) -> Vertex4<T> {
+        Vertex4::new(x, y, z, T::ONE)
     }
     #[inline]
-    pub const fn vector(x: f32, y: f32, z: f32) -> Vert4 {
-        Vert4::new(x, y, z, 0.0)
+    pub const fn vector(x: T, y: T, z: T) -> Vertex4<T> {
+        Vertex4::new(x, y, z, T::ZERO)
     }
     #[inline]
-    pub const fn is_point(&self) -> bool {
-        self.w() == 1.
+    pub fn is_point(&self) -> bool {
+        self.w() == T::ONE
     }
     #[inline]
-    pub const fn is_vector(&self) -> bool {
+    pub fn is_vector(&self) -> bool {
         !self.is_point()
     }
     #[inline]
-    pub const fn from_slice(slice: &[f32]) -> Vert4 {
-        Vert4(f32x4::from_slice(slice))
+    pub const fn from_slice(slice: &[T]) -> Vertex4<T> {
+        Vertex4(Simd::from_slice(slice))
     }
 
     #[inline]
-    pub const fn from_array(array: [f32; 4]) -> Vert4 {
-        Vert4(f32x4::from_array(array))
-    }
-    #[inline]
-    pub fn reduce_sum(self) -> f32 {
-        self.0.reduce_sum()
-    }
-}
-// From impls---------
-impl From<f32x4> for Vert4 {
-    #[inline]
-    fn from(value: f32x4) -> Self {
-        Self(value)
-    }
-}
-impl From<&f32x4> for Vert4 {
-    #[inline]
-    fn from(value: &f32x4) -> Self {
-        Self(value.to_owned())
-    }
-}
-#[expect(unused_macros)]
-macro_rules! imp {
-    (Add, Self = $t:ty $(, Rhs = $rhs:ty)?$(, Output = $o:ty)?$(, Ex = $e:expr)?) => {
-        add_impl!(Self = $t$(, Rhs = $rhs)?$(, Output = $o)?$(, Ex = $e)?);
-    };
-    (AddAssign, Self = $t:ty$(, Rhs = $rhs:ty)?, Ex = $e:expr) => {
-        add_assign_impl!(Self = $t$(, Rhs = $rhs)?, Ex = $e);
-    };
-}
-#[expect(unused_macros)]
-macro_rules! add_assign_impl {
-    (Self = $t:ty, Rhs = $rhs:ty, Ex = $e:expr) => {
-        impl AddAssign<$rhs> for $t {
-            #[inline]
-            fn add_assign(&mut self, rhs: $rhs) {
-                $e
-            }
-        }
-    };
-    (Self = $t:ty, Ex = $e:expr) => {
-        impl AddAssign for $t {
-            #[inline]
-            fn add_assign(&mut self, rhs: $t) {
-                $e
-            }
-        }
-    };
-}
-// Add impls---------
-#[expect(unused_macros)]
-macro_rules! add_impl {
-    (Self = $t:ty, Ex = $e:expr) => {
-        add_impl!(Self = $t, Rhs = $t, Output = $t, Ex = $e);
-    };
-    (Self = $t:ty) => {
-        add_impl!(Self = $t, Rhs = $t, Output = $t);
-    };
-    (Self = $t:ty, Rhs = $rhs:ty, Ex = $e:expr) => {
-        add_impl!(Self = $t, Rhs = $rhs, Output = $t, Ex = $e);
-    };
-    (Self = $t:ty, Rhs = $rhs:ty) => {
-        add_impl!(Self = $t, Rhs = $rhs, Output = $t);
-    };
-    (Self = $t:ty, Rhs = $rhs:ty, Output = $o:ty) => {
-        impl Add<$rhs> for $t {
-            type Output = $o;
-            #[inline]
-            fn add(self, rhs: $rhs) -> Self::Output {
-                self.0.add(rhs.0).into()
-            }
-        }
-    };
-    (Self = $t:ty, Rhs = $rhs:ty, Output = $o:ty, Ex = $e:expr) => {
-        impl Add<$rhs> for $t {
-            type Output = $o;
-            #[inline]
-            fn add(self, rhs: $rhs) -> Self::Output {
-                self.0.add(rhs.0).into()
-            }
-        }
-    };
-}
-impl Add for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.0.add(rhs.0).into()
-    }
-}
-impl AddAssign for Vert4 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        self.0.add_assign(rhs.0)
-    }
-}
-impl Add<&Self> for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn add(self, rhs: &Self) -> Self::Output {
-        self.0.add(rhs.0).into()
-    }
-}
-impl AddAssign<&Self> for Vert4 {
-    #[inline]
-    fn add_assign(&mut self, rhs: &Self) {
-        self.0.add_assign(rhs.0)
-    }
-}
-impl Add for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.0.add(rhs.0).into()
-    }
-}
-impl Add<&Self> for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn add(self, rhs: &Self) -> Self::Output {
-        self.0.add(rhs.0).into()
-    }
-}
-impl Sub for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.0.sub(rhs.0).into()
-    }
-}
-impl SubAssign for Vert4 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0.sub_assign(rhs.0)
-    }
-}
-impl Sub<&Self> for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn sub(self, rhs: &Self) -> Self::Output {
-        self.0.sub(rhs.0).into()
-    }
-}
-impl SubAssign<&Self> for Vert4 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: &Self) {
-        self.0.sub_assign(rhs.0)
-    }
-}
-impl Sub<&Self> for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn sub(self, rhs: &Self) -> Self::Output {
-        self.0.sub(rhs.0).into()
-    }
-}
-impl Neg for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn neg(self) -> Self::Output {
-        self.0.neg().into()
-    }
-}
-impl Neg for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn neg(self) -> Self::Output {
-        self.0.neg().into()
-    }
-}
-impl Mul<f32> for Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: f32) -> Self::Output {
-        self.0.mul(simd_4!(rhs)).into()
-    }
-}
-impl Mul<f32> for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: f32) -> Self::Output {
-        self.0.mul(simd_4!(rhs)).into()
-    }
-}
-impl Mul<&f32> for Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: &f32) -> Self::Output {
-        self.0.mul(simd_4!(*rhs)).into()
-    }
-}
-impl MulAssign for Vert4 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
-        self.0.mul_assign(rhs.0)
-    }
-}
-impl Mul<Vert4> for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: Vert4) -> Self::Output {
-        self.0.mul(rhs.0).into()
-    }
-}
-impl Mul<&Self> for Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: &Self) -> Self::Output {
-        self.0.mul(rhs.0).into()
+    pub const fn from_array(array: [T; 4]) -> Vertex4<T> {
+        Vertex4(Simd::from_array(array))
     }
-}
-impl MulAssign<&Self> for Vert4 {
     #[inline]
-    fn mul_assign(&mut self, rhs: &Self) {
-        self.0.mul_assign(rhs.0)
+    pub const fn as_simd(&self) -> &Simd<T, 4> {
+        &self.0
     }
 }
-impl Mul<Self> for &Vert4 {
-    type Output = Vert4;
-    #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.0.mul(rhs.0).into()
-    }
-}
-impl Div<f32> for Vert4 {
-    type Output = Self;
-    #[inline]
-    fn div(self, rhs: f32) -> Self::Output {
-        self.0.div(simd_4!(rhs)).into()
-    }
-}
-impl DivAssign<f32> for Vert4 {
+impl<T: Scalar> Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
     #[inline]
-    fn div_assign(&mut self, rhs: f32) {
-        self.0.div_assign(simd_4!(rhs))
+    pub fn reduce_sum(self) -> T {
+        self.0.reduce_sum()
     }
-}
-impl Div<f32> for &Vert4 {
-    type Output = Vert4;
+    /// Divides by `w`, mapping a clip space position to a normalized device
+    /// coordinate point
     #[inline]
-    fn div(self, rhs: f32) -> Self::Output {
-        self.0.div(simd_4!(rhs)).into()
+    pub fn perspective_divide(&self) -> Vertex4<T> {
+        debug_assert!(self.w() != T::ZERO, "perspective_divide: w is zero");
+        let ndc = self.0 / Simd::splat(self.w());
+        Vertex4::point(ndc[0], ndc[1], ndc[2])
     }
-}
-impl Div<&f32> for Vert4 {
-    type Output = Self;
+    /// Mirrors the vector about `normal`, which must be normalized
     #[inline]
-    fn div(self, rhs: &f32) -> Self::Output {
-        self.0.div(simd_4!(*rhs)).into()
+    pub fn reflect(&self, normal: &Vertex4<T>) -> Vertex4<T> {
+        let scale = Simd::splat(T::TWO * (self.0 * normal.0).reduce_sum());
+        Vertex4(self.0 - normal.0 * scale)
     }
 }
-impl DivAssign<&f32> for Vert4 {
+// From impls---------
+impl<T: Scalar> From<Simd<T, 4>> for Vertex4<T> {
     #[inline]
-    fn div_assign(&mut self, rhs: &f32) {
-        self.0.div_assign(simd_4!(*rhs))
+    fn from(value: Simd<T, 4>) -> Self {
+        Self(value)
     }
 }
-impl Div<&f32> for &Vert4 {
-    type Output = Vert4;
+impl<T: Scalar> From<&Simd<T, 4>> for Vertex4<T> {
     #[inline]
-    fn div(self, rhs: &f32) -> Self::Output {
-        self.0.div(simd_4!(*rhs)).into()
+    fn from(value: &Simd<T, 4>) -> Self {
+        Self(value.to_owned())
     }
 }
-impl PartialEq for Vert4 {
+// Vert4 impls---------
+binop_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Add, add, AddAssign, add_assign, |l, r| l + r);
+binop_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Sub, sub, SubAssign, sub_assign, |l, r| l - r);
+binop_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| l * r);
+scalar_op_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Mul, mul, MulAssign, mul_assign, |l, r| l * Simd::splat(r));
+scalar_op_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Div, div, DivAssign, div_assign, |l, r| l / Simd::splat(r));
+neg_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], |v| -v);
+impl<T: Scalar> PartialEq for Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         simd_almost_eq(&self.0, &other.0)
@@ -453,81 +278,68 @@ pub trait Dot<Rhs: ?Sized = Self> {
     type Output;
     fn dot(self, rhs: Rhs) -> Self::Output;
 }
-impl Dot for Vert4 {
-    type Output = f32;
-    fn dot(self, rhs @ Vert4(rhs_simd): Self) -> Self::Output {
-        debug_assert!(self.is_vector(), "Dot: Self is not a vector!");
-        debug_assert!(rhs.is_vector(), "Dot: Rhs is not a vector!");
-        Simd::mul(self.0, rhs_simd).reduce_sum()
-    }
-}
-impl Dot<&Vert4> for Vert4 {
-    type Output = f32;
-    fn dot(self, rhs @ Vert4(rhs_simd): &Vert4) -> Self::Output {
-        debug_assert!(self.is_vector(), "Dot: Self is not a vector!");
-        debug_assert!(rhs.is_vector(), "Dot: Rhs is not a vector!");
-        Simd::mul(self.0, rhs_simd).reduce_sum()
-    }
-}
-impl Dot for &Vert4 {
-    type Output = f32;
-    fn dot(self, rhs @ Vert4(rhs_simd): Self) -> Self::Output {
-        debug_assert!(self.is_vector(), "Dot: Self is not a vector!");
-        debug_assert!(rhs.is_vector(), "Dot: Rhs is not a vector!");
-        Simd::mul(self.0, rhs_simd).reduce_sum()
-    }
-}
-impl Dot<Vert4> for &Vert4 {
-    type Output = f32;
-    fn dot(self, rhs @ Vert4(rhs_simd): Vert4) -> Self::Output {
-        debug_assert!(self.is_vector(), "Dot: Self is not a vector!");
-        debug_assert!(rhs.is_vector(), "Dot: Rhs is not a vector!");
-        Simd::mul(self.0, rhs_simd).reduce_sum()
-    }
-}
+product_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Dot, dot, T, |l, r| {
+    debug_assert!(Vertex4(l).is_vector(), "Dot: Self is not a vector!");
+    debug_assert!(Vertex4(r).is_vector(), "Dot: Rhs is not a vector!");
+    (l * r).reduce_sum()
+});
 pub trait Mag {
     type Output;
     fn mag(&self) -> Self::Output;
 }
-impl Mag for f32x4 {
-    type Output = f32;
+impl<T: Scalar> Mag for Simd<T, 4>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = T;
     #[inline]
     fn mag(&self) -> Self::Output {
-        self.mul(self).reduce_sum().sqrt()
+        (*self * *self).reduce_sum().sqrt()
     }
 }
-impl Mag for Vert4 {
-    type Output = f32;
+impl<T: Scalar> Mag for Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = T;
     fn mag(&self) -> Self::Output {
-        self.mul(self).reduce_sum().sqrt()
+        self.0.mag()
     }
 }
 pub trait Norm {
     type Output;
     fn norm(&self) -> Self::Output;
 }
-impl Norm for f32x4 {
-    type Output = f32x4;
+impl<T: Scalar> Norm for Simd<T, 4>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Simd<T, 4>;
     #[inline]
     fn norm(&self) -> Self::Output {
-        self / f32x4::from_array([self.mag(); 4])
+        *self / Simd::splat(self.mag())
     }
 }
-impl Norm for Vert4 {
-    type Output = Vert4;
+impl<T: Scalar> Norm for Vertex4<T>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
+    type Output = Vertex4<T>;
     #[inline]
     fn norm(&self) -> Self::Output {
         self / self.mag()
-        // Vert4(self.0 / f32x4::from_array([self.0.mag(); 4]))
     }
 }
 pub trait NormAssign {
     fn norm_assign(&mut self);
 }
-impl NormAssign for f32x4 {
+impl<T: Scalar> NormAssign for Simd<T, 4>
+where
+    Simd<T, 4>: SimdOps<T, 4>,
+{
     #[inline]
     fn norm_assign(&mut self) {
-        *self /= f32x4::from_array([self.mag(); 4])
+        *self /= Simd::splat(self.mag())
     }
 }
 pub trait Cross<Rhs: ?Sized> {
@@ -536,59 +348,69 @@ pub trait Cross<Rhs: ?Sized> {
 }
 pub(crate) const CROSS_SWIZZLE_0: [usize; 4] = [1, 2, 0, 3];
 pub(crate) const CROSS_SWIZZLE_1: [usize; 4] = [2, 0, 1, 3];
-impl Cross<Vert4> for Vert4 {
-    type Output = Vert4;
+product_impl!(Vertex4 [Simd<T, 4>: SimdOps<T, 4>], Cross, cross, Vertex4<T>, |l, r| {
+    let prod_0 = simd_swizzle!(l, CROSS_SWIZZLE_0) * simd_swizzle!(r, CROSS_SWIZZLE_1);
+    let prod_1 = simd_swizzle!(l, CROSS_SWIZZLE_1) * simd_swizzle!(r, CROSS_SWIZZLE_0);
+    Vertex4(prod_0 - prod_1)
+});
+// Vert2 impls---------
+binop_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Add, add, AddAssign, add_assign, |l, r| l + r);
+binop_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Sub, sub, SubAssign, sub_assign, |l, r| l - r);
+binop_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Mul, mul, MulAssign, mul_assign, |l, r| l * r);
+scalar_op_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Mul, mul, MulAssign, mul_assign, |l, r| l * Simd::splat(r));
+scalar_op_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Div, div, DivAssign, div_assign, |l, r| l / Simd::splat(r));
+neg_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], |v| -v);
+product_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Dot, dot, T, |l, r| (l * r).reduce_sum());
+// The 2D cross product is the perp-dot product, the z-component of the 3D cross product
+product_impl!(Vertex2 [Simd<T, 2>: SimdOps<T, 2>], Cross, cross, T, |l, r| (l[0] * r[1]) - (l[1] * r[0]));
+impl<T: Scalar> Mag for Vertex2<T>
+where
+    Simd<T, 2>: SimdOps<T, 2>,
+{
+    type Output = T;
     #[inline]
-    fn cross(self, rhs: Vert4) -> Self::Output {
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_0);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_1);
-        let prod_0: f32x4 = temp_0 * temp_1;
-
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_1);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_0);
-        let prod_1: f32x4 = temp_0 * temp_1;
-        Self(prod_0 - prod_1)
+    fn mag(&self) -> Self::Output {
+        (self.0 * self.0).reduce_sum().sqrt()
     }
 }
-impl Cross<Vert4> for &Vert4 {
-    type Output = Vert4;
+impl<T: Scalar> Norm for Vertex2<T>
+where
+    Simd<T, 2>: SimdOps<T, 2>,
+{
+    type Output = Vertex2<T>;
     #[inline]
-    fn cross(self, rhs: Vert4) -> Self::Output {
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_0);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_1);
-        let prod_0: f32x4 = temp_0 * temp_1;
-
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_1);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_0);
-        let prod_1: f32x4 = temp_0 * temp_1;
-        Vert4(prod_0 - prod_1)
+    fn norm(&self) -> Self::Output {
+        self / self.mag()
     }
 }
-impl Cross<&Vert4> for Vert4 {
-    type Output = Vert4;
+// Vert3 impls---------
+#[inline]
+fn zip_3<T: Scalar>(lhs: [T; 3], rhs: [T; 3], f: impl Fn(T, T) -> T) -> [T; 3] {
+    [f(lhs[0], rhs[0]), f(lhs[1], rhs[1]), f(lhs[2], rhs[2])]
+}
+binop_impl!(Vertex3 [], Add, add, AddAssign, add_assign, |l, r| zip_3(l, r, T::add));
+binop_impl!(Vertex3 [], Sub, sub, SubAssign, sub_assign, |l, r| zip_3(l, r, T::sub));
+binop_impl!(Vertex3 [], Mul, mul, MulAssign, mul_assign, |l, r| zip_3(l, r, T::mul));
+scalar_op_impl!(Vertex3 [], Mul, mul, MulAssign, mul_assign, |l, r| l.map(|l| l * r));
+scalar_op_impl!(Vertex3 [], Div, div, DivAssign, div_assign, |l, r| l.map(|l| l / r));
+neg_impl!(Vertex3 [], |v| v.map(T::neg));
+product_impl!(Vertex3 [], Dot, dot, T, |l, r| zip_3(l, r, T::mul).into_iter().sum());
+product_impl!(Vertex3 [], Cross, cross, Vertex3<T>, |l, r| Vertex3([
+    (l[1] * r[2]) - (l[2] * r[1]),
+    (l[2] * r[0]) - (l[0] * r[2]),
+    (l[0] * r[1]) - (l[1] * r[0]),
+]));
+impl<T: Scalar> Mag for Vertex3<T> {
+    type Output = T;
     #[inline]
-    fn cross(self, rhs: &Vert4) -> Self::Output {
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_0);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_1);
-        let prod_0: f32x4 = temp_0 * temp_1;
-
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_1);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_0);
-        let prod_1: f32x4 = temp_0 * temp_1;
-        Self(prod_0 - prod_1)
+    fn mag(&self) -> Self::Output {
+        self.0.iter().map(|&elt| elt * elt).sum::<T>().sqrt()
     }
 }
-impl Cross<&Vert4> for &Vert4 {
-    type Output = Vert4;
+impl<T: Scalar> Norm for Vertex3<T> {
+    type Output = Vertex3<T>;
     #[inline]
-    fn cross(self, rhs: &Vert4) -> Self::Output {
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_0);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_1);
-        let prod_0: f32x4 = temp_0 * temp_1;
-
-        let temp_0: f32x4 = simd_swizzle!(self.0, CROSS_SWIZZLE_1);
-        let temp_1: f32x4 = simd_swizzle!(rhs.0, CROSS_SWIZZLE_0);
-        let prod_1: f32x4 = temp_0 * temp_1;
-        Vert4(prod_0 - prod_1)
+    fn norm(&self) -> Self::Output {
+        self / self.mag()
     }
 }
@@ -0,0 +1,196 @@
+//! Scenes of shapes and lights, and the recursive ray tracer over them.
+
+use core::ptr;
+
+use crate::camera::Camera;
+use crate::canvas::Canvas;
+use crate::color::Color;
+use crate::lighting::{PointLight, lighting_object};
+use crate::ray::{InvalidRay, Ray};
+use crate::shapes::{EPSILON, Intersection, Intersections, Shape};
+use crate::vertex::{Dot, Mag, Norm, Vert4};
+
+/// Bounces a ray may take off reflective and through transparent surfaces
+pub const MAX_DEPTH: usize = 5;
+
+#[derive(Debug, Default)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    pub lights: Vec<PointLight>,
+}
+/// What shading an intersection needs, computed once from the hit
+#[derive(Debug)]
+pub struct Computations<'a> {
+    pub t: f32,
+    pub object: &'a dyn Shape,
+    pub point: Vert4,
+    /// Towards the eye, normalized
+    pub eyev: Vert4,
+    /// Normalized, flipped to face the eye
+    pub normalv: Vert4,
+    /// Whether the hit is on the inside of the surface
+    pub inside: bool,
+    /// `point` nudged along the normal, off the surface, for secondary rays
+    /// leaving it without hitting it again
+    pub over_point: Vert4,
+    /// `point` nudged below the surface, where refracted rays start
+    pub under_point: Vert4,
+    pub reflectv: Vert4,
+    /// Refractive index of the material the ray leaves
+    pub n1: f32,
+    /// Refractive index of the material the ray enters
+    pub n2: f32,
+}
+impl<'a> Computations<'a> {
+    /// `xs` are all the intersections along `ray`, sorted, `hit` among them.
+    /// `n1` and `n2` come from tracking which objects contain the ray at the
+    /// hit, the outermost being vacuum. `None` if the object hit has no
+    /// surface, see [`Shape::local_normal_at`].
+    pub fn prepare(hit: &Intersection<'a>, ray: &Ray, xs: &[Intersection<'a>]) -> Option<Computations<'a>> {
+        let point = ray.position(hit.t);
+        let eyev = -&ray.direction;
+        let mut normalv = hit.object.normal_at(&point)?;
+        let inside = Dot::dot(&normalv, &eyev) < 0.;
+        if inside {
+            normalv = -normalv;
+        }
+        let over_point = point + normalv * EPSILON;
+        let under_point = point - normalv * EPSILON;
+        let reflectv = ray.direction.reflect(&normalv);
+
+        let (mut n1, mut n2) = (1., 1.);
+        let mut containers: Vec<&dyn Shape> = Vec::new();
+        for i in xs {
+            let is_hit = i == hit;
+            if is_hit {
+                n1 = containers.last().map_or(1., |object| object.material().refractive_index);
+            }
+            match containers.iter().position(|object| ptr::addr_eq(*object, i.object)) {
+                Some(index) => {
+                    containers.remove(index);
+                }
+                None => containers.push(i.object),
+            }
+            if is_hit {
+                n2 = containers.last().map_or(1., |object| object.material().refractive_index);
+                break;
+            }
+        }
+        Some(Computations { t: hit.t, object: hit.object, point, eyev, normalv, inside, over_point, under_point, reflectv, n1, n2 })
+    }
+    /// Share of the light reflected, Schlick's approximation of the Fresnel
+    /// equations. `1` under total internal reflection.
+    pub fn schlick(&self) -> f32 {
+        let mut cos = Dot::dot(&self.eyev, &self.normalv);
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n * n * (1. - cos * cos);
+            if sin2_t > 1. {
+                return 1.;
+            }
+            cos = (1. - sin2_t).sqrt();
+        }
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+        r0 + (1. - r0) * (1. - cos).powi(5)
+    }
+}
+impl World {
+    #[inline]
+    pub fn new() -> World {
+        World::default()
+    }
+    #[inline]
+    pub fn add_object<S: Shape + 'static>(&mut self, object: S) {
+        self.objects.push(Box::new(object));
+    }
+    #[inline]
+    pub fn add_light(&mut self, light: PointLight) {
+        self.lights.push(light);
+    }
+    /// Every intersection with every object, sorted by `t`. Fails if the
+    /// ray's origin is not a point or its direction not a vector.
+    pub fn intersect(&self, ray: &Ray) -> Result<Intersections<'_>, InvalidRay> {
+        let mut xs = Vec::new();
+        for object in &self.objects {
+            xs.extend(object.intersect(ray)?);
+        }
+        Ok(xs.into_iter().collect())
+    }
+    /// Whether something is between `point` and `light`, stopping at the
+    /// first object found. Fails if `point` or the light's position is not a
+    /// point.
+    pub fn is_shadowed(&self, point: &Vert4, light: &PointLight) -> Result<bool, InvalidRay> {
+        let to_light = light.position - point;
+        let distance = to_light.mag();
+        let ray = Ray::new(*point, to_light.norm());
+        for object in &self.objects {
+            if object.any_hit(&ray, distance)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+    /// The colour at the hit, from every light, plus what is reflected and
+    /// refracted there while `remaining` bounces are left. A light whose
+    /// position is not a point casts no shadows.
+    pub fn shade_hit(&self, comps: &Computations, remaining: usize) -> Color {
+        let material = comps.object.material();
+        let surface = self.lights.iter().fold(Color::BLACK, |color, light| {
+            let shadowed = self.is_shadowed(&comps.over_point, light).unwrap_or(false);
+            color + lighting_object(comps.object, light, &comps.over_point, &comps.eyev, &comps.normalv, shadowed)
+        });
+        let reflected = self.reflected_color(comps, remaining);
+        let refracted = self.refracted_color(comps, remaining);
+        if material.reflective > 0. && material.transparency > 0. {
+            let reflectance = comps.schlick();
+            surface + reflected * reflectance + refracted * (1. - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
+    }
+    /// The colour seen along `ray`, black if it hits nothing or is not a valid
+    /// ray
+    #[inline]
+    pub fn color_at(&self, ray: &Ray) -> Color {
+        self.color_at_depth(ray, MAX_DEPTH)
+    }
+    /// [`World::color_at`] with `remaining` bounces left
+    pub fn color_at_depth(&self, ray: &Ray, remaining: usize) -> Color {
+        let xs = self.intersect(ray).unwrap_or_default();
+        match xs.hit().and_then(|hit| Computations::prepare(hit, ray, &xs)) {
+            Some(comps) => self.shade_hit(&comps, remaining),
+            None => Color::BLACK,
+        }
+    }
+    /// Black for non-reflective surfaces and once no bounces are left
+    pub fn reflected_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let reflective = comps.object.material().reflective;
+        if reflective == 0. || remaining == 0 {
+            return Color::BLACK;
+        }
+        let reflected = Ray::new(comps.over_point, comps.reflectv);
+        self.color_at_depth(&reflected, remaining - 1) * reflective
+    }
+    /// Black for opaque surfaces, under total internal reflection and once no
+    /// bounces are left
+    pub fn refracted_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let transparency = comps.object.material().transparency;
+        if transparency == 0. || remaining == 0 {
+            return Color::BLACK;
+        }
+        // Snell's law
+        let n_ratio = comps.n1 / comps.n2;
+        let cos_i = Dot::dot(&comps.eyev, &comps.normalv);
+        let sin2_t = n_ratio * n_ratio * (1. - cos_i * cos_i);
+        if sin2_t > 1. {
+            return Color::BLACK;
+        }
+        let cos_t = (1. - sin2_t).sqrt();
+        let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+        self.color_at_depth(&Ray::new(comps.under_point, direction), remaining - 1) * transparency
+    }
+    #[inline]
+    pub fn render(&self, camera: &Camera) -> Canvas {
+        camera.render(|ray| self.color_at(ray))
+    }
+}
@@ -0,0 +1,143 @@
+use core::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_4, SQRT_2};
+
+use geometry::{Aabb, Matr4, Ray, Vert4};
+
+fn unit() -> Aabb {
+    Aabb::new(&Vert4::point(-1., -1., -1.), &Vert4::point(1., 1., 1.))
+}
+//
+// Construction tests
+//
+#[test]
+fn creating_a_box_orders_the_corners() {
+    let b = Aabb::new(&Vert4::point(1., -2., 3.), &Vert4::point(-1., 2., -3.));
+    assert_eq!(b.min(), &Vert4::point(-1., -2., -3.));
+    assert_eq!(b.max(), &Vert4::point(1., 2., 3.));
+}
+#[test]
+fn the_empty_box_is_empty() {
+    assert!(Aabb::empty().is_empty());
+    assert!(!unit().is_empty());
+    assert_eq!(Aabb::empty().surface_area(), 0.);
+}
+#[test]
+fn a_box_around_points() {
+    let points = [Vert4::point(-5., 2., 0.), Vert4::point(7., 0., -3.), Vert4::point(1., 8., 4.)];
+    let b = Aabb::from_points(&points);
+    assert_eq!(b.min(), &Vert4::point(-5., 0., -3.));
+    assert_eq!(b.max(), &Vert4::point(7., 8., 4.));
+}
+//
+// Query tests
+//
+#[test]
+fn merging_two_boxes() {
+    let a = Aabb::new(&Vert4::point(-5., -2., 0.), &Vert4::point(7., 4., 4.));
+    let b = Aabb::new(&Vert4::point(8., -7., -2.), &Vert4::point(14., 2., 8.));
+    let m = a.merge(&b);
+    assert_eq!(m.min(), &Vert4::point(-5., -7., -2.));
+    assert_eq!(m.max(), &Vert4::point(14., 4., 8.));
+    assert_eq!(Aabb::empty().merge(&a), a);
+}
+#[test]
+fn a_box_contains_points_inside_and_on_its_faces() {
+    let b = Aabb::new(&Vert4::point(5., -2., 0.), &Vert4::point(11., 4., 7.));
+    assert!(b.contains(&Vert4::point(5., -2., 0.)));
+    assert!(b.contains(&Vert4::point(11., 4., 7.)));
+    assert!(b.contains(&Vert4::point(8., 1., 3.)));
+    assert!(!b.contains(&Vert4::point(3., 0., 3.)));
+    assert!(!b.contains(&Vert4::point(8., -4., 3.)));
+    assert!(!b.contains(&Vert4::point(8., 1., 8.)));
+}
+#[test]
+fn boxes_intersect_when_they_overlap() {
+    let a = unit();
+    assert!(a.intersects(&Aabb::new(&Vert4::point(0.5, 0.5, 0.5), &Vert4::point(3., 3., 3.))));
+    assert!(a.intersects(&Aabb::new(&Vert4::point(1., -1., -1.), &Vert4::point(2., 1., 1.))));
+    assert!(!a.intersects(&Aabb::new(&Vert4::point(1.5, 0., 0.), &Vert4::point(2., 1., 1.))));
+    assert!(!a.intersects(&Aabb::empty()));
+}
+#[test]
+fn centre_extents_and_surface_area() {
+    let b = Aabb::new(&Vert4::point(-1., 0., 2.), &Vert4::point(3., 2., 3.));
+    assert_eq!(b.centre(), Vert4::point(1., 1., 2.5));
+    assert_eq!(b.extents(), Vert4::vector(4., 2., 1.));
+    assert_eq!(b.surface_area(), 2. * (8. + 2. + 4.));
+}
+//
+// Transformation tests
+//
+#[test]
+fn transforming_a_box_bounds_the_rotated_corners() {
+    let m = &Matr4::rotation_y_rad(FRAC_PI_4) * &Matr4::rotation_x_rad(FRAC_PI_4);
+    let b = unit().transform(&m);
+    let (k, l) = (1. + FRAC_1_SQRT_2, SQRT_2);
+    assert_eq!(b.min(), &Vert4::point(-k, -l, -k));
+    assert_eq!(b.max(), &Vert4::point(k, l, k));
+}
+#[test]
+fn transforming_a_box_by_a_translation() {
+    let b = unit().transform(&Matr4::translation(1., 2., 3.));
+    assert_eq!(b, Aabb::new(&Vert4::point(0., 1., 2.), &Vert4::point(2., 3., 4.)));
+}
+#[test]
+fn transforming_an_unbounded_box_leaves_it_unbounded() {
+    // Like a plane: infinite in x and z, flat in y
+    let slab = Aabb::new(&Vert4::point(f32::NEG_INFINITY, 0., f32::NEG_INFINITY), &Vert4::point(f32::INFINITY, 0., f32::INFINITY));
+    assert!(!slab.is_finite() && unit().is_finite());
+    let b = slab.transform(&Matr4::rotation_x_rad(FRAC_PI_4));
+    assert_eq!(b, Aabb::infinite());
+    assert!(!b.min().x().is_nan() && !b.max().y().is_nan());
+    assert_eq!(Aabb::empty().transform(&Matr4::translation(1., 2., 3.)), Aabb::empty());
+}
+#[test]
+fn cloning_a_box() {
+    let b = unit();
+    assert_eq!(b.clone(), b);
+}
+//
+// Slab tests
+//
+#[test]
+fn a_ray_intersects_a_box() {
+    let b = unit();
+    let cases = [
+        (Vert4::point(5., 0.5, 0.), Vert4::vector(-1., 0., 0.), 4.),
+        (Vert4::point(-5., 0.5, 0.), Vert4::vector(1., 0., 0.), 4.),
+        (Vert4::point(0.5, 5., 0.), Vert4::vector(0., -1., 0.), 4.),
+        (Vert4::point(0.5, 0., -5.), Vert4::vector(0., 0., 1.), 4.),
+        (Vert4::point(-3., -3., -3.), Vert4::vector(1., 1., 1.), 2.),
+        (Vert4::point(0., 0.5, 0.), Vert4::vector(0., 0., 1.), 0.),
+    ];
+    for (origin, direction, t) in cases {
+        let hit = b.intersect_ray(&Ray::new(origin, direction), 0., f32::INFINITY);
+        assert_eq!(hit, Some(t));
+    }
+}
+#[test]
+fn a_ray_misses_a_box() {
+    let b = unit();
+    let cases = [
+        (Vert4::point(-2., 0., 0.), Vert4::vector(0.2673, 0.5345, 0.8018)),
+        (Vert4::point(0., -2., 0.), Vert4::vector(0.8018, 0.2673, 0.5345)),
+        (Vert4::point(2., 0., 2.), Vert4::vector(0., 0., -1.)),
+        (Vert4::point(0., 2., 2.), Vert4::vector(0., -1., 0.)),
+        (Vert4::point(0., 0., 5.), Vert4::vector(0., 0., 1.)),
+    ];
+    for (origin, direction) in cases {
+        assert_eq!(b.intersect_ray(&Ray::new(origin, direction), 0., f32::INFINITY), None);
+    }
+}
+#[test]
+fn the_slab_test_respects_the_interval() {
+    let b = unit();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    assert_eq!(b.intersect_ray(&r, 0., 3.), None);
+    assert_eq!(b.intersect_ray(&r, 5., 10.), Some(5.));
+}
+#[test]
+fn a_ray_along_a_face_of_a_box() {
+    let b = unit();
+    let r = Ray::new(Vert4::point(1., 0., -5.), Vert4::vector(0., 0., 1.));
+    assert_eq!(b.intersect_ray(&r, 0., f32::INFINITY), Some(4.));
+}
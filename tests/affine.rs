@@ -0,0 +1,64 @@
+use geometry::{Matr4, Matrix, Point, Vector, Vert4};
+#[test]
+fn subtracting_two_points_gives_a_vector() {
+    let p1 = Point::new(3.0, 2.0, 1.0);
+    let p2 = Point::new(5.0, 6.0, 7.0);
+    assert_eq!(p1 - p2, Vector::new(-2.0, -4.0, -6.0))
+}
+#[test]
+fn adding_a_vector_to_a_point_gives_a_point() {
+    let p = Point::new(3.0, -2.0, 5.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
+    assert_eq!(&p + &v, Point::new(1.0, 1.0, 6.0));
+    assert_eq!(v + p, Point::new(1.0, 1.0, 6.0));
+}
+#[test]
+fn subtracting_a_vector_from_a_point_gives_a_point() {
+    let mut p = Point::new(3.0, 2.0, 1.0);
+    p -= Vector::new(5.0, 6.0, 7.0);
+    assert_eq!(p, Point::new(-2.0, -4.0, -6.0))
+}
+#[test]
+fn vector_arithmetic() {
+    let a = Vector::new(1.0, -2.0, 3.0);
+    let b = Vector::new(2.0, 3.0, 4.0);
+    assert_eq!(&a + &b, Vector::new(3.0, 1.0, 7.0));
+    assert_eq!(&a - &b, Vector::new(-1.0, -5.0, -1.0));
+    assert_eq!(-&a, Vector::new(-1.0, 2.0, -3.0));
+    assert_eq!(&a * 3.5, Vector::new(3.5, -7.0, 10.5));
+    assert_eq!(a / 2.0, Vector::new(0.5, -1.0, 1.5));
+}
+#[test]
+fn vector_products_and_normalizing() {
+    use geometry::vertex::{Cross, Dot, Mag, Norm};
+    let a = Vector::new(1.0, 2.0, 3.0);
+    let b = Vector::new(2.0, 3.0, 4.0);
+    assert_eq!(Dot::dot(&a, &b), 20.0);
+    assert_eq!(Cross::cross(&a, &b), Vector::new(-1.0, 2.0, -1.0));
+    assert_eq!(a.mag(), 14.0_f32.sqrt());
+    assert_eq!(a.norm(), Vector::new(0.26726, 0.53452, 0.80178));
+}
+#[test]
+fn transforming_keeps_the_kind() {
+    let transform = Matr4::translation(5., -3., 2.);
+    let p: Point = &transform * Point::new(-3., 4., 5.);
+    assert_eq!(p, Point::new(2., 1., 7.));
+    let v: Vector = transform * Vector::new(-3., 4., 5.);
+    assert_eq!(v, Vector::new(-3., 4., 5.));
+}
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "bottom row")]
+fn projections_do_not_keep_the_kind() {
+    let mut projection = Matr4::identity();
+    projection[(3, 2)] = -1.;
+    let _ = projection * Point::new(1., 2., 3.);
+}
+#[test]
+fn converting_from_vertices() {
+    assert_eq!(Point::try_from(Vert4::point(1., 2., 3.)), Ok(Point::new(1., 2., 3.)));
+    assert!(Point::try_from(Vert4::vector(1., 2., 3.)).is_err());
+    assert_eq!(Vector::try_from(Vert4::vector(1., 2., 3.)), Ok(Vector::new(1., 2., 3.)));
+    assert!(Vector::try_from(Vert4::new(1., 2., 3., 2.)).is_err());
+    assert_eq!(Vert4::from(Point::new(1., 2., 3.)), Vert4::point(1., 2., 3.));
+}
@@ -0,0 +1,169 @@
+use geometry::shapes::{Shape, Sphere, Triangle};
+use geometry::{Aabb, Bvh, Ray, Transform, Vert4};
+
+// xorshift, enough to scatter primitives reproducibly
+struct Rng(u32);
+impl Rng {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32
+    }
+    fn range(&mut self, low: f32, high: f32) -> f32 {
+        low + (high - low) * self.next()
+    }
+    fn point(&mut self, extent: f32) -> Vert4 {
+        Vert4::point(self.range(-extent, extent), self.range(-extent, extent), self.range(-extent, extent))
+    }
+}
+fn triangles(rng: &mut Rng, count: usize) -> Vec<Triangle> {
+    (0..count)
+        .map(|_| {
+            let centre = rng.point(20.);
+            let corner = |rng: &mut Rng| centre + (rng.point(1.) - Vert4::point(0., 0., 0.));
+            Triangle::new(corner(rng), corner(rng), corner(rng))
+        })
+        .collect()
+}
+fn rays(rng: &mut Rng, count: usize) -> Vec<Ray> {
+    (0..count)
+        .map(|_| {
+            let origin = rng.point(30.);
+            let target = rng.point(20.);
+            Ray::new(origin, target - origin)
+        })
+        .collect()
+}
+fn nearest_hit(shape: &dyn Shape, ray: &Ray) -> Option<f32> {
+    shape.intersect(ray).unwrap().hit().map(|i| i.t)
+}
+fn brute_force(shapes: &[&dyn Shape], ray: &Ray) -> Option<(usize, f32)> {
+    shapes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, shape)| nearest_hit(*shape, ray).map(|t| (i, t)))
+        .min_by(|l, r| l.1.total_cmp(&r.1))
+}
+//
+// Construction tests
+//
+#[test]
+fn an_empty_hierarchy_hits_nothing() {
+    let bvh = Bvh::build(&[]);
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    assert!(bvh.is_empty());
+    assert_eq!(bvh.bounds(), None);
+    assert_eq!(bvh.closest_hit(&r, f32::INFINITY, |_, _| Some(1.)), None);
+    assert!(!bvh.any_hit(&r, f32::INFINITY, |_, _| true));
+}
+#[test]
+fn every_primitive_is_in_exactly_one_leaf() {
+    let mut rng = Rng(0x2545f491);
+    let triangles = triangles(&mut rng, 500);
+    let bounds: Vec<_> = triangles.iter().map(|t| (t as &dyn Shape).world_bounds()).collect();
+    let bvh = Bvh::build(&bounds);
+
+    let mut seen = vec![0; triangles.len()];
+    for node in bvh.nodes().iter().filter(|node| node.is_leaf()) {
+        for &primitive in &bvh.primitives()[node.primitive_range()] {
+            seen[primitive] += 1;
+            assert!(node.bounds().contains(bounds[primitive].min()));
+            assert!(node.bounds().contains(bounds[primitive].max()));
+        }
+    }
+    assert!(seen.iter().all(|&count| count == 1));
+    assert!(bvh.nodes().len() < 2 * triangles.len());
+}
+#[test]
+fn the_root_bounds_everything() {
+    let bounds = [
+        Aabb::new(&Vert4::point(-3., 0., 0.), &Vert4::point(-2., 1., 1.)),
+        Aabb::new(&Vert4::point(4., -2., 0.), &Vert4::point(5., 1., 6.)),
+        Aabb::new(&Vert4::point(0., 0., -7.), &Vert4::point(1., 1., 1.)),
+    ];
+    let bvh = Bvh::build(&bounds);
+    assert_eq!(bvh.bounds(), Some(&Aabb::new(&Vert4::point(-3., -2., -7.), &Vert4::point(5., 1., 6.))));
+}
+//
+// Query tests
+//
+#[test]
+fn closest_hit_matches_brute_force_on_triangles() {
+    let mut rng = Rng(0x9e3779b9);
+    let triangles = triangles(&mut rng, 1000);
+    let shapes: Vec<&dyn Shape> = triangles.iter().map(|t| t as &dyn Shape).collect();
+    let bounds: Vec<_> = shapes.iter().map(|s| s.world_bounds()).collect();
+    let bvh = Bvh::build(&bounds);
+
+    let mut hits = 0;
+    for ray in rays(&mut rng, 500) {
+        let expected = brute_force(&shapes, &ray);
+        let found = bvh.closest_hit(&ray, f32::INFINITY, |i, _| nearest_hit(shapes[i], &ray));
+        assert_eq!(found, expected);
+        assert_eq!(bvh.any_hit(&ray, f32::INFINITY, |i, _| nearest_hit(shapes[i], &ray).is_some()), expected.is_some());
+        hits += expected.is_some() as usize;
+    }
+    assert!(hits > 0);
+}
+#[test]
+fn candidates_include_every_primitive_hit() {
+    let mut rng = Rng(0x85ebca6b);
+    let triangles = triangles(&mut rng, 1000);
+    let shapes: Vec<&dyn Shape> = triangles.iter().map(|t| t as &dyn Shape).collect();
+    let bounds: Vec<_> = shapes.iter().map(|s| s.world_bounds()).collect();
+    let bvh = Bvh::build(&bounds);
+
+    for ray in rays(&mut rng, 200) {
+        let mut candidates = Vec::new();
+        bvh.for_each_candidate(&ray, f32::NEG_INFINITY, f32::INFINITY, |i| candidates.push(i));
+        assert!(candidates.len() < shapes.len());
+        for (i, shape) in shapes.iter().enumerate() {
+            if !shape.intersect(&ray).unwrap().is_empty() {
+                assert!(candidates.contains(&i));
+            }
+        }
+    }
+}
+#[test]
+fn any_hit_respects_the_maximum_distance() {
+    let spheres: Vec<Sphere> =
+        (0..10).map(|i| Sphere::new().with_transform(Transform::identity().translate(0., 0., 3. * i as f32))).collect();
+    let shapes: Vec<&dyn Shape> = spheres.iter().map(|s| s as &dyn Shape).collect();
+    let bounds: Vec<_> = shapes.iter().map(|s| s.world_bounds()).collect();
+    let bvh = Bvh::build(&bounds);
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let hits_within = |t_max: f32| bvh.any_hit(&r, t_max, |i, t_max| nearest_hit(shapes[i], &r).is_some_and(|t| t < t_max));
+
+    assert!(!hits_within(3.));
+    assert!(hits_within(4.5));
+    assert_eq!(bvh.closest_hit(&r, f32::INFINITY, |i, _| nearest_hit(shapes[i], &r)), Some((0, 4.)));
+}
+#[test]
+fn refitting_follows_moved_primitives() {
+    let mut rng = Rng(0x1b873593);
+    let mut spheres: Vec<Sphere> =
+        (0..200).map(|_| Sphere::new().with_transform(Transform::identity().translate(rng.range(-20., 20.), rng.range(-20., 20.), rng.range(-20., 20.)))).collect();
+    let bounds: Vec<_> = spheres.iter().map(|s| (s as &dyn Shape).world_bounds()).collect();
+    let mut bvh = Bvh::build(&bounds);
+
+    for sphere in &mut spheres {
+        let moved = Transform::identity().scale(2., 2., 2.).translate(5., 0., 0.);
+        let transform = moved * sphere.transform();
+        sphere.set_transform(transform);
+    }
+    let shapes: Vec<&dyn Shape> = spheres.iter().map(|s| s as &dyn Shape).collect();
+    let bounds: Vec<_> = shapes.iter().map(|s| s.world_bounds()).collect();
+    bvh.refit(&bounds);
+
+    for node in bvh.nodes().iter().filter(|node| node.is_leaf()) {
+        for &primitive in &bvh.primitives()[node.primitive_range()] {
+            assert!(node.bounds().contains(bounds[primitive].min()));
+            assert!(node.bounds().contains(bounds[primitive].max()));
+        }
+    }
+    for ray in rays(&mut rng, 300) {
+        let found = bvh.closest_hit(&ray, f32::INFINITY, |i, _| nearest_hit(shapes[i], &ray));
+        assert_eq!(found, brute_force(&shapes, &ray));
+    }
+}
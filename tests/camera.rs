@@ -0,0 +1,78 @@
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use geometry::shapes::{Shape, Sphere};
+use geometry::{Camera, Color, Transform, Vert4};
+//
+// Construction tests
+//
+#[test]
+fn constructing_a_camera() {
+    let c = Camera::new(160, 120, FRAC_PI_2);
+    assert_eq!((c.hsize(), c.vsize(), c.field_of_view()), (160, 120, FRAC_PI_2));
+    assert_eq!(c.transform(), &Transform::identity());
+}
+#[test]
+fn the_pixel_size_for_a_horizontal_canvas() {
+    let c = Camera::new(200, 125, FRAC_PI_2);
+    assert!((c.pixel_size() - 0.01).abs() < 1e-6);
+    assert!((c.half_width() - 1.).abs() < 1e-6 && (c.half_height() - 0.625).abs() < 1e-6);
+}
+#[test]
+fn the_pixel_size_for_a_vertical_canvas() {
+    let c = Camera::new(125, 200, FRAC_PI_2);
+    assert!((c.pixel_size() - 0.01).abs() < 1e-6);
+    assert!((c.half_width() - 0.625).abs() < 1e-6 && (c.half_height() - 1.).abs() < 1e-6);
+}
+//
+// Ray tests
+//
+#[test]
+fn constructing_a_ray_through_the_center_of_the_canvas() {
+    let c = Camera::new(201, 101, FRAC_PI_2);
+    let r = c.ray_for_pixel(100, 50);
+    assert_eq!(r.origin, Vert4::point(0., 0., 0.));
+    assert_eq!(r.direction, Vert4::vector(0., 0., -1.));
+}
+#[test]
+fn constructing_a_ray_through_a_corner_of_the_canvas() {
+    let c = Camera::new(201, 101, FRAC_PI_2);
+    let r = c.ray_for_pixel(0, 0);
+    assert_eq!(r.origin, Vert4::point(0., 0., 0.));
+    assert_eq!(r.direction, Vert4::vector(0.66519, 0.33259, -0.66851));
+}
+#[test]
+fn constructing_a_ray_when_the_camera_is_transformed() {
+    let c = Camera::new(201, 101, FRAC_PI_2)
+        .with_transform(Transform::identity().translate(0., -2., 5.).rotate_y(FRAC_PI_4));
+    let r = c.ray_for_pixel(100, 50);
+    let k = 2f32.sqrt() / 2.;
+    assert_eq!(r.origin, Vert4::point(0., 2., -5.));
+    assert_eq!(r.direction, Vert4::vector(k, 0., -k));
+}
+#[test]
+fn a_view_transform_points_the_camera() {
+    let view = Transform::identity().view(Vert4::point(0., 0., -5.), Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.));
+    let c = Camera::new(11, 11, FRAC_PI_2).with_transform(view);
+    let r = c.ray_for_pixel(5, 5);
+    assert_eq!(r.origin, Vert4::point(0., 0., -5.));
+    assert_eq!(r.direction, Vert4::vector(0., 0., 1.));
+}
+//
+// Render tests
+//
+#[test]
+fn rendering_a_sphere_with_a_camera() {
+    let view = Transform::identity().view(Vert4::point(0., 0., -5.), Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.));
+    let c = Camera::new(11, 11, FRAC_PI_2).with_transform(view);
+    let sphere = Sphere::new();
+    let image = c.render(|ray| match (&sphere as &dyn Shape).intersect(ray).unwrap().hit() {
+        Some(_) => Color::WHITE,
+        None => Color::BLACK,
+    });
+    assert_eq!((image.width(), image.height()), (11, 11));
+    assert_eq!(image.pixel_at(5, 5), &Color::WHITE);
+    assert_eq!(image.pixel_at(0, 0), &Color::BLACK);
+    // At its distance of 5 the view is 10 wide and the sphere 2, about 2.2 of 11 pixels
+    let lit = (0..11).filter(|&x| *image.pixel_at(x, 5) == Color::WHITE).count();
+    assert_eq!(lit, 3);
+}
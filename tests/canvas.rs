@@ -0,0 +1,160 @@
+use geometry::canvas::PpmError;
+use geometry::{Canvas, Color, Vert4, clock};
+
+fn color(r: f32, g: f32, b: f32) -> Color {
+    Color::new(r, g, b)
+}
+//
+// Pixel tests
+//
+#[test]
+fn creating_a_canvas() {
+    let c = Canvas::new(10, 20);
+    assert_eq!(c.width(), 10);
+    assert_eq!(c.height(), 20);
+    assert!(c.pixels().iter().all(|pixel| *pixel == color(0., 0., 0.)));
+}
+#[test]
+fn writing_pixels_to_a_canvas() {
+    let mut c = Canvas::new(10, 20);
+    c.write_pixel(2, 3, color(1., 0., 0.));
+    assert_eq!(c.pixel_at(2, 3), &color(1., 0., 0.));
+    assert_eq!(c.pixel_at(3, 2), &color(0., 0., 0.));
+}
+#[test]
+#[should_panic]
+fn writing_outside_a_canvas() {
+    Canvas::new(10, 20).write_pixel(10, 0, color(1., 0., 0.));
+}
+#[test]
+fn drawing_a_clock() {
+    let mut c = Canvas::new(100, 100);
+    for hour in clock(Vert4::point(0., 0., 40.)) {
+        let (x, y) = ((50. + hour.x()).round() as usize, (50. - hour.z()).round() as usize);
+        c.write_pixel(x, y, color(1., 1., 1.));
+    }
+    let lit = c.pixels().iter().filter(|pixel| **pixel == color(1., 1., 1.)).count();
+    assert_eq!(lit, 12);
+    assert_eq!(c.pixel_at(50, 10), &color(1., 1., 1.));
+    assert_eq!(c.pixel_at(90, 50), &color(1., 1., 1.));
+    assert_eq!(c.pixel_at(50, 50), &color(0., 0., 0.));
+}
+//
+// PPM export tests
+//
+#[test]
+fn constructing_the_ppm_header() {
+    let ppm = Canvas::new(5, 3).to_ppm();
+    assert_eq!(ppm.lines().take(3).collect::<Vec<_>>(), ["P3", "5 3", "255"]);
+}
+#[test]
+fn constructing_the_ppm_pixel_data() {
+    let mut c = Canvas::new(5, 3);
+    c.write_pixel(0, 0, color(1.5, 0., 0.));
+    c.write_pixel(2, 1, color(0., 0.5, 0.));
+    c.write_pixel(4, 2, color(-0.5, 0., 1.));
+    let ppm = c.to_ppm();
+    assert_eq!(
+        ppm.lines().skip(3).collect::<Vec<_>>(),
+        [
+            "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+            "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+            "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+        ]
+    );
+}
+#[test]
+fn splitting_long_lines_in_ppm_files() {
+    let mut c = Canvas::new(10, 2);
+    for y in 0..2 {
+        for x in 0..10 {
+            c.write_pixel(x, y, color(1., 0.8, 0.6));
+        }
+    }
+    let ppm = c.to_ppm();
+    assert_eq!(
+        ppm.lines().skip(3).collect::<Vec<_>>(),
+        [
+            "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+            "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+            "153 255 204 153 255 204 153 255 204 153 255 204 153",
+        ]
+    );
+    assert!(ppm.lines().all(|line| line.len() <= 70));
+}
+#[test]
+fn ppm_files_are_terminated_by_a_newline() {
+    assert!(Canvas::new(5, 3).to_ppm().ends_with('\n'));
+}
+//
+// PPM import tests
+//
+#[test]
+fn reading_a_file_with_the_wrong_magic_number() {
+    assert_eq!(Canvas::from_ppm(b"P32\n1 1\n255\n0 0 0\n"), Err(PpmError::UnsupportedFormat));
+}
+#[test]
+fn reading_a_plain_ppm_returns_a_canvas() {
+    let ppm = b"P3\n4 3\n255\n255 127 0  0 127 255  127 255 0  255 255 255\n\
+                0 0 0  255 0 0  0 255 0  0 0 255\n\
+                255 255 0  0 255 255  255 0 255  127 127 127\n";
+    let c = Canvas::from_ppm(ppm).unwrap();
+    assert_eq!((c.width(), c.height()), (4, 3));
+    let k = 127. / 255.;
+    assert_eq!(c.pixel_at(0, 0), &color(1., k, 0.));
+    assert_eq!(c.pixel_at(1, 0), &color(0., k, 1.));
+    assert_eq!(c.pixel_at(3, 0), &color(1., 1., 1.));
+    assert_eq!(c.pixel_at(1, 1), &color(1., 0., 0.));
+    assert_eq!(c.pixel_at(3, 2), &color(k, k, k));
+}
+#[test]
+fn ppm_parsing_ignores_comments() {
+    let ppm = b"P3\n# this is a comment\n2 1\n# this, too\n255\n# another comment\n255 255 255\n# oh, no, comments in the pixel data!\n255 0 255\n";
+    let c = Canvas::from_ppm(ppm).unwrap();
+    assert_eq!(c.pixel_at(0, 0), &color(1., 1., 1.));
+    assert_eq!(c.pixel_at(1, 0), &color(1., 0., 1.));
+}
+#[test]
+fn ppm_parsing_respects_the_scale_setting() {
+    let c = Canvas::from_ppm(b"P3\n2 2\n100\n100 100 100  50 50 50\n75 50 25  0 0 0\n").unwrap();
+    assert_eq!(c.pixel_at(0, 1), &color(0.75, 0.5, 0.25));
+}
+#[test]
+fn reading_a_binary_ppm() {
+    let mut ppm = b"P6\n2 1\n255\n".to_vec();
+    ppm.extend_from_slice(&[255, 0, 51, 0, 102, 255]);
+    let c = Canvas::from_ppm(&ppm).unwrap();
+    assert_eq!(c.pixel_at(0, 0), &color(1., 0., 0.2));
+    assert_eq!(c.pixel_at(1, 0), &color(0., 0.4, 1.));
+}
+#[test]
+fn reading_a_binary_ppm_with_two_byte_samples() {
+    let mut ppm = b"P6 1 1 65535\n".to_vec();
+    ppm.extend_from_slice(&[0xff, 0xff, 0x80, 0x00, 0x00, 0x00]);
+    let c = Canvas::from_ppm(&ppm).unwrap();
+    assert_eq!(c.pixel_at(0, 0), &color(1., 32768. / 65535., 0.));
+}
+#[test]
+fn reading_malformed_ppm_files() {
+    assert_eq!(Canvas::from_ppm(b"P3\n2 1\n255\n0 0 0 0 0\n"), Err(PpmError::UnexpectedEnd));
+    assert_eq!(Canvas::from_ppm(b"P3\n2 x\n255\n"), Err(PpmError::InvalidNumber));
+    assert_eq!(Canvas::from_ppm(b"P3\n1 1\n255\n0 256 0\n"), Err(PpmError::OutOfRange));
+    assert_eq!(Canvas::from_ppm(b"P3\n4294967296 4294967296\n255\n"), Err(PpmError::OutOfRange));
+    assert_eq!(Canvas::from_ppm(b"P6\n2 1\n255\n\x00\x00\x00\x00"), Err(PpmError::UnexpectedEnd));
+}
+#[test]
+fn exporting_and_importing_round_trips() {
+    let mut c = Canvas::new(30, 4);
+    for y in 0..4 {
+        for x in 0..30 {
+            c.write_pixel(x, y, color(x as f32 / 29., y as f32 / 3., 0.2));
+        }
+    }
+    let read = Canvas::from_ppm(c.to_ppm().as_bytes()).unwrap();
+    assert_eq!((read.width(), read.height()), (30, 4));
+    for (written, read) in c.pixels().iter().zip(read.pixels()) {
+        assert!((written.r() - read.r()).abs() <= 0.5 / 255. + 1e-6);
+        assert!((written.g() - read.g()).abs() <= 0.5 / 255. + 1e-6);
+    }
+}
@@ -32,3 +32,43 @@ fn chained_transformations_must_be_applied_in_reverse_order() {
     let t = c_matr() * b_matr() * a_matr() * initial_point();
     assert_eq!(t, Vert4::point(15., 0., 7.));
 }
+#[test]
+fn the_transform_builder_applies_in_reading_order() {
+    use geometry::Transform;
+
+    let t: Matr4 = Transform::identity()
+        .rotate_x(PI / 2.)
+        .scale(5., 5., 5.)
+        .translate(10., 5., 7.)
+        .into();
+    assert_eq!(t, c_matr() * b_matr() * a_matr());
+    assert_eq!(t * initial_point(), Vert4::point(15., 0., 7.));
+}
+#[test]
+fn the_transform_builder_reuses_the_constructors() {
+    use geometry::matrix::ShearingProportion;
+    use geometry::{Matrix, Transform};
+
+    let shear = || {
+        (
+            ShearingProportion::new(1., 0.),
+            ShearingProportion::default(),
+            ShearingProportion::new(0., 2.),
+        )
+    };
+    let (x, y, z) = shear();
+    let t = Transform::identity()
+        .shear(x, y, z)
+        .rotate_y(0.5)
+        .rotate_z(-0.25)
+        .rotate_axis(Vert4::vector(1., 1., 0.), 1.)
+        .then(Transform::try_from(Matr4::scaling(1., 2., 3.)).unwrap());
+    let (x, y, z) = shear();
+    let expected = Matr4::scaling(1., 2., 3.)
+        * Matr4::rotation_axis_angle(Vert4::vector(1., 1., 0.), 1.)
+        * Matr4::rotation_z_rad(-0.25)
+        * Matr4::rotation_y_rad(0.5)
+        * Matr4::shearing(x, y, z);
+    assert_eq!(t.matrix(), &expected);
+    assert_eq!(Transform::<f32>::identity().matrix(), &Matr4::identity());
+}
@@ -0,0 +1,81 @@
+use geometry::Color;
+//
+// Construction tests
+//
+#[test]
+fn colors_are_red_green_blue_tuples() {
+    let c = Color::new(-0.5, 0.4, 1.7);
+    assert_eq!((c.r(), c.g(), c.b(), c.a()), (-0.5, 0.4, 1.7, 1.));
+    let c = Color::rgba(0.1, 0.2, 0.3, 0.5);
+    assert_eq!(c.a(), 0.5);
+    assert_eq!(Color::default(), Color::BLACK);
+}
+//
+// Operator tests
+//
+#[test]
+fn adding_colors() {
+    assert_eq!(Color::new(0.9, 0.6, 0.75) + Color::new(0.7, 0.1, 0.25), Color::new(1.6, 0.7, 1.0));
+}
+#[test]
+fn subtracting_colors() {
+    assert_eq!(Color::new(0.9, 0.6, 0.75) - Color::new(0.7, 0.1, 0.25), Color::new(0.2, 0.5, 0.5));
+}
+#[test]
+fn multiplying_a_color_by_a_scalar() {
+    assert_eq!(Color::new(0.2, 0.3, 0.4) * 2., Color::new(0.4, 0.6, 0.8));
+    assert_eq!(Color::new(0.2, 0.3, 0.4) / 2., Color::new(0.1, 0.15, 0.2));
+}
+#[test]
+fn multiplying_colors() {
+    assert_eq!(Color::new(1., 0.2, 0.4) * Color::new(0.9, 1., 0.1), Color::new(0.9, 0.2, 0.04));
+}
+#[test]
+fn arithmetic_keeps_the_alpha_of_the_left_operand() {
+    let l = Color::rgba(0.1, 0.2, 0.3, 0.5);
+    let r = Color::rgba(0.3, 0.2, 0.1, 0.25);
+    assert_eq!((l + r).a(), 0.5);
+    assert_eq!((l - r).a(), 0.5);
+    assert_eq!((&l * 4.).a(), 0.5);
+    assert_eq!((l * r).a(), 0.125);
+    let mut c = Color::new(0.1, 0.1, 0.1);
+    c += Color::new(0.2, 0.2, 0.2);
+    c *= 2.;
+    assert_eq!(c, Color::new(0.6, 0.6, 0.6));
+}
+#[test]
+fn clamping_a_color() {
+    assert_eq!(Color::new(-0.5, 0.4, 1.7).clamp(), Color::new(0., 0.4, 1.));
+}
+#[test]
+fn colors_compare_approximately() {
+    assert_eq!(Color::new(0.1, 0.2, 0.3), Color::new(0.10001, 0.2, 0.3));
+    assert_ne!(Color::new(0.1, 0.2, 0.3), Color::new(0.11, 0.2, 0.3));
+}
+//
+// Conversion tests
+//
+#[test]
+fn converting_to_8_bit() {
+    assert_eq!(Color::new(1.5, 0.5, -0.5).to_rgb8(), [255, 128, 0]);
+    assert_eq!(Color::rgba(0.2, 0.8, 0.6, 0.).to_rgba8(), [51, 204, 153, 0]);
+    assert_eq!(Color::from_rgba8([51, 204, 153, 255]), Color::new(0.2, 0.8, 0.6));
+}
+#[test]
+fn converting_to_srgb() {
+    assert_eq!(Color::BLACK.to_srgb(), Color::BLACK);
+    assert_eq!(Color::WHITE.to_srgb(), Color::WHITE);
+    // 18% grey is about half way up the encoded range
+    assert_eq!(Color::new(0.18, 0.18, 0.18).to_srgb().to_rgb8(), [118, 118, 118]);
+    // The linear segment near black
+    assert_eq!(Color::new(0.001, 0., 0.).to_srgb(), Color::new(0.01292, 0., 0.));
+    assert_eq!(Color::rgba(0.5, 0.5, 0.5, 0.5).to_srgb().a(), 0.5);
+}
+#[test]
+fn srgb_round_trips() {
+    for i in 0..=20 {
+        let v = i as f32 / 20.;
+        let c = Color::new(v, v * v, 1. - v);
+        assert_eq!(c.to_srgb().to_linear(), c);
+    }
+}
@@ -0,0 +1,84 @@
+use geometry::{Color, Material, PointLight, Vert4, lighting};
+
+fn setup() -> (Material, Vert4) {
+    (Material::default(), Vert4::point(0., 0., 0.))
+}
+//
+// Material tests
+//
+#[test]
+fn the_default_material() {
+    let m = Material::default();
+    assert_eq!(m.color, Color::new(1., 1., 1.));
+    assert_eq!((m.ambient, m.diffuse, m.specular, m.shininess), (0.1, 0.9, 0.9, 200.));
+    assert_eq!((m.reflective, m.transparency, m.refractive_index), (0., 0., 1.));
+}
+#[test]
+fn a_point_light_has_a_position_and_intensity() {
+    let light = PointLight::new(Vert4::point(0., 0., 0.), Color::new(1., 1., 1.));
+    assert_eq!(light.position, Vert4::point(0., 0., 0.));
+    assert_eq!(light.intensity, Color::WHITE);
+}
+//
+// Lighting tests
+//
+#[test]
+fn lighting_with_the_eye_between_the_light_and_the_surface() {
+    let (m, position) = setup();
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., -10.), Color::WHITE);
+    assert_eq!(lighting(&m, &light, &position, &eyev, &normalv, false), Color::new(1.9, 1.9, 1.9));
+}
+#[test]
+fn lighting_with_the_eye_between_light_and_surface_eye_offset_45_degrees() {
+    let (m, position) = setup();
+    let k = 2f32.sqrt() / 2.;
+    let eyev = Vert4::vector(0., k, -k);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., -10.), Color::WHITE);
+    assert_eq!(lighting(&m, &light, &position, &eyev, &normalv, false), Color::new(1., 1., 1.));
+}
+#[test]
+fn lighting_with_eye_opposite_surface_light_offset_45_degrees() {
+    let (m, position) = setup();
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 10., -10.), Color::WHITE);
+    assert_eq!(lighting(&m, &light, &position, &eyev, &normalv, false), Color::new(0.7364, 0.7364, 0.7364));
+}
+#[test]
+fn lighting_with_eye_in_the_path_of_the_reflection_vector() {
+    let (m, position) = setup();
+    let k = 2f32.sqrt() / 2.;
+    let eyev = Vert4::vector(0., -k, -k);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 10., -10.), Color::WHITE);
+    let result = lighting(&m, &light, &position, &eyev, &normalv, false);
+    assert!((result.r() - 1.6364).abs() < 1e-3, "{result:?}");
+}
+#[test]
+fn lighting_with_the_light_behind_the_surface() {
+    let (m, position) = setup();
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., 10.), Color::WHITE);
+    assert_eq!(lighting(&m, &light, &position, &eyev, &normalv, false), Color::new(0.1, 0.1, 0.1));
+}
+#[test]
+fn lighting_with_the_surface_in_shadow() {
+    let (m, position) = setup();
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., -10.), Color::WHITE);
+    assert_eq!(lighting(&m, &light, &position, &eyev, &normalv, true), Color::new(0.1, 0.1, 0.1));
+}
+#[test]
+fn lighting_tints_by_the_light_and_material_colors() {
+    let m = Material { color: Color::new(1., 0.5, 0.), specular: 0., ..Material::default() };
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., -10.), Color::new(0.5, 1., 1.));
+    let result = lighting(&m, &light, &Vert4::point(0., 0., 0.), &eyev, &normalv, false);
+    assert_eq!(result, Color::new(0.5, 0.5, 0.));
+}
@@ -132,7 +132,7 @@ mod mat4 {
         #[test]
         fn multiplication_of_vertex_by_ident() {
             let a = geometry::vertex!(1.0, 2.0, 3.0, 4.0);
-            assert_eq!(Matr4::identity() * &a, a)
+            assert_eq!(Matr4::identity() * a, a)
         }
     }
     #[test]
@@ -258,5 +258,55 @@ mod mat4 {
             let m1_m2_prod_i2_prod = m1_m2_prod * &i2;
             assert_eq!(m1_m2_prod_i2_prod, m1);
         }
+        #[test]
+        fn inverse_matches_the_cofactor_expansion() {
+            use geometry::matrix::FromArray;
+            use geometry::Matr4;
+
+            let matrices = [
+                geometry::mat4! {
+                    3.0, -9.0, 7.0, 3.0, 3.0, -8.0, 2.0, -9.0, -4.0, 4.0, 4.0, 1.0, -6.0, 5.0, -1.0,
+                    1.0,
+                },
+                Matr4::translation(1., -2., 3.) * Matr4::rotation_y_rad(0.3) * Matr4::scaling(2., 1., 0.5),
+                geometry::mat4! {
+                    0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+                },
+            ];
+            for m in matrices {
+                let det: f32 = (0..4).map(|col| m[(0, col)] * m.cofactor(0, col)).sum();
+                assert!((m.determinant() - det).abs() < 1e-3);
+                let adjugate: [f32; 16] = core::array::from_fn(|idx| m.cofactor(idx % 4, idx / 4) / det);
+                assert_eq!(m.inverse().unwrap(), Matr4::from_array(adjugate));
+            }
+        }
+    }
+}
+mod dmat4 {
+    use geometry::matrix::{Cofactor, Determinant, Inverse};
+    use geometry::{DMatr4, DVert4, Matrix};
+    #[test]
+    fn calculating_determinant() {
+        let a = geometry::mat4! {f64;
+            -2, -8, 3, 5, -3, 1, 7, 3, 1, 2, -9, 6, -6, 7, 7, -9,
+        };
+        assert_eq!(a.cofactor(0, 0), 690.0);
+        assert_eq!(a.determinant(), -4071.0);
+    }
+    #[test]
+    fn calculating_inverse() {
+        let a = geometry::mat4! {f64;
+            -5, 2, 6, -8, 1, -5, 1, 8, 7, 7, -6, -7, 1, -3, 7, 4,
+        };
+        let i = a.inverse().unwrap();
+        assert_eq!(i[(3, 2)], -160.0 / 532.0);
+        assert_eq!(i[(2, 3)], 105.0 / 532.0);
+        assert_eq!(&a * &i, DMatr4::identity());
+    }
+    #[test]
+    fn transforming_a_point() {
+        let transform = DMatr4::translation(5., -3., 2.) * DMatr4::rotation_z_deg(90.);
+        let p = DVert4::point(0., 1., 0.);
+        assert_eq!(transform * p, DVert4::point(4., -3., 2.));
     }
 }
@@ -1,6 +1,7 @@
 use geometry::matrix::Inverse;
 use geometry::matrix::ShearingProportion;
-use geometry::{Matr4, Vert4};
+use geometry::matrix::AsColumns;
+use geometry::{Dot, Matr4, Matrix, Vert4};
 //
 // Translation tests
 //
@@ -26,7 +27,7 @@ fn multiplying_by_the_inverse_of_a_translation_matrix() {
 fn translation_does_not_affect_vectors() {
     let transform = Matr4::translation(5., -3., 2.);
     let v = Vert4::vector(-3., 4., 5.);
-    let res = transform * &v;
+    let res = transform * v;
     assert_eq!(res, v)
 }
 //
@@ -71,14 +72,14 @@ fn rotating_a_point_around_the_x_axis() {
     let p = Vert4::point(0., 1., 0.);
 
     let half_quarter = Matr4::rotation_x_rad(PI / 4.);
-    let half_rotp = half_quarter * &p;
+    let half_rotp = half_quarter * p;
     assert_eq!(
         half_rotp,
         Vert4::point(0., 2_f32.sqrt() / 2., 2_f32.sqrt() / 2.)
     );
 
     let full_quarter = Matr4::rotation_x_rad(PI / 2.);
-    let full_rotp = full_quarter * &p;
+    let full_rotp = full_quarter * p;
     assert_eq!(full_rotp, Vert4::point(0., 0., 1.))
 }
 #[test]
@@ -89,7 +90,7 @@ fn the_inverse_of_an_x_rotation_rotates_in_the_oppsite_direction() {
 
     let half_quarter = Matr4::rotation_x_rad(PI / 4.);
     let inv = half_quarter.inverse().unwrap();
-    let half_rotp = inv * &p;
+    let half_rotp = inv * p;
     assert_eq!(
         half_rotp,
         Vert4::point(0., 2_f32.sqrt() / 2., -(2_f32.sqrt() / 2.))
@@ -102,14 +103,14 @@ fn rotating_a_point_around_the_y_axis() {
     let p = Vert4::point(0., 0., 1.);
 
     let half_quarter = Matr4::rotation_y_rad(PI / 4.);
-    let half_rotp = half_quarter * &p;
+    let half_rotp = half_quarter * p;
     assert_eq!(
         half_rotp,
         Vert4::point(2_f32.sqrt() / 2., 0., 2_f32.sqrt() / 2.)
     );
 
     let full_quarter = Matr4::rotation_y_rad(PI / 2.);
-    let full_rotp = full_quarter * &p;
+    let full_rotp = full_quarter * p;
     assert_eq!(full_rotp, Vert4::point(1., 0., 0.))
 }
 #[test]
@@ -119,16 +120,47 @@ fn rotating_a_point_around_the_z_axis() {
     let p = Vert4::point(0., 1., 0.);
 
     let half_quarter = Matr4::rotation_z_rad(PI / 4.);
-    let half_rotp = half_quarter * &p;
+    let half_rotp = half_quarter * p;
     assert_eq!(
         half_rotp,
         Vert4::point(-2_f32.sqrt() / 2., 2_f32.sqrt() / 2., 0.)
     );
 
     let full_quarter = Matr4::rotation_z_rad(PI / 2.);
-    let full_rotp = full_quarter * &p;
+    let full_rotp = full_quarter * p;
     assert_eq!(full_rotp, Vert4::point(-1., 0., 0.))
 }
+#[test]
+fn rotating_around_a_principal_axis_matches_the_axis_rotations() {
+    let angle = 1.1;
+    assert_eq!(Matr4::rotation_axis_angle(Vert4::X, angle), Matr4::rotation_x_rad(angle));
+    assert_eq!(Matr4::rotation_axis_angle(Vert4::Y, angle), Matr4::rotation_y_rad(angle));
+    assert_eq!(Matr4::rotation_axis_angle(Vert4::Z * 5., angle), Matr4::rotation_z_rad(angle));
+}
+#[test]
+fn rotating_a_point_around_an_arbitrary_axis() {
+    use core::f32::consts::PI;
+
+    // A third of a turn about the diagonal cycles the axes
+    let transform = Matr4::rotation_axis_angle(Vert4::vector(1., 1., 1.), 2. * PI / 3.);
+    assert_eq!(&transform * Vert4::point(1., 0., 0.), Vert4::point(0., 1., 0.));
+    assert_eq!(&transform * Vert4::point(0., 1., 0.), Vert4::point(0., 0., 1.));
+    let axis_point = Vert4::point(2., 2., 2.);
+    assert_eq!(&transform * axis_point, axis_point);
+    let inv = transform.inverse().unwrap();
+    assert_eq!(inv * Vert4::point(0., 1., 0.), Vert4::point(1., 0., 0.));
+}
+#[test]
+fn typed_rotations_about_a_runtime_axis() {
+    use geometry::matrix::{RotationAbout, X};
+    use geometry::Norm;
+
+    let axis = Vert4::vector(0., 3., -4.).norm();
+    let typed = Matr4::from(RotationAbout::from_axis_deg(Vert4::vector(0., 0.6, -0.8), 30.));
+    assert_eq!(typed, Matr4::rotation_axis_angle(axis, 30_f32.to_radians()));
+    let marker: RotationAbout<X> = RotationAbout::new_deg(30.);
+    assert_eq!(Matr4::from(marker), Matr4::rotation_x_deg(30.));
+}
 //
 // Shearing tests
 //
@@ -192,3 +224,228 @@ fn a_shearing_transformation_moves_z_in_proportion_to_y() {
     let p = Vert4::point(2., 3., 4.);
     assert_eq!(transform * p, Vert4::point(2., 3., 7.))
 }
+//
+// Affine inverse tests
+//
+#[test]
+fn the_affine_inverse_matches_the_general_inverse() {
+    let transform = Matr4::translation(5., -3., 2.)
+        * Matr4::rotation_axis_angle(Vert4::vector(1., 2., 3.), 0.8)
+        * Matr4::scaling(2., -1., 0.5)
+        * Matr4::shearing(
+            ShearingProportion::new(1., 0.),
+            ShearingProportion::default(),
+            ShearingProportion::new(0., 2.),
+        );
+    assert!(transform.is_affine());
+    assert_eq!(transform.inverse_affine().unwrap(), transform.inverse().unwrap());
+    assert!(Matr4::scaling(1., 0., 1.).inverse_affine().is_none());
+}
+#[test]
+fn the_rigid_inverse_undoes_the_transform() {
+    let transform = Matr4::translation(1., -2., 3.) * Matr4::rotation_x_rad(0.4) * Matr4::rotation_z_rad(-1.2);
+    let inv = transform.inverse_rigid();
+    assert_eq!(inv, transform.inverse().unwrap());
+    let p = Vert4::point(-4., 6., 8.);
+    assert_eq!(inv * (&transform * p), p);
+}
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "bottom row")]
+fn the_affine_inverse_rejects_projections() {
+    let mut projection = Matr4::identity();
+    projection[(3, 2)] = -1.;
+    assert!(!projection.is_affine());
+    let _ = projection.inverse_affine();
+}
+//
+// View transformation tests
+//
+#[test]
+fn the_view_transformation_for_the_default_orientation() {
+    let from = Vert4::point(0., 0., 0.);
+    let to = Vert4::point(0., 0., -1.);
+    let up = Vert4::vector(0., 1., 0.);
+    assert_eq!(Matr4::view_transform(from, to, up), Matr4::identity());
+}
+#[test]
+fn a_view_transformation_looking_in_positive_z_direction() {
+    let from = Vert4::point(0., 0., 0.);
+    let to = Vert4::point(0., 0., 1.);
+    let up = Vert4::vector(0., 1., 0.);
+    assert_eq!(Matr4::view_transform(from, to, up), Matr4::scaling(-1., 1., -1.));
+}
+#[test]
+fn the_view_transformation_moves_the_world() {
+    let from = Vert4::point(0., 0., 8.);
+    let to = Vert4::point(0., 0., 0.);
+    let up = Vert4::vector(0., 1., 0.);
+    assert_eq!(Matr4::view_transform(from, to, up), Matr4::translation(0., 0., -8.));
+}
+#[test]
+fn an_arbitrary_view_transformation() {
+    use geometry::Norm;
+
+    let from = Vert4::point(1., 3., 2.);
+    let to = Vert4::point(4., -2., 8.);
+    let up = Vert4::vector(1., 1., 0.);
+    let transform = Matr4::view_transform(Vert4::point(1., 3., 2.), Vert4::point(4., -2., 8.), up);
+    assert_eq!(&transform * from, Vert4::point(0., 0., 0.));
+    assert_eq!(&transform * (to - from).norm(), Vert4::vector(0., 0., -1.));
+    // `up` is not perpendicular to the forward direction, the axes are still orthonormal
+    let [x, y, z, w] = transform.as_row_vectors().map(|row| Vert4::vector(row[0], row[1], row[2]));
+    assert_eq!(w, Vert4::ZERO);
+    for axis in [&x, &y, &z] {
+        assert_eq!(axis.norm(), *axis);
+    }
+    for product in [Dot::dot(&x, &y), Dot::dot(&y, &z), Dot::dot(&z, &x)] {
+        assert!(product.abs() < 1e-5);
+    }
+    assert_eq!(z, -(to - from).norm());
+}
+#[test]
+fn the_left_handed_view_transformation() {
+    let default = Matr4::view_transform_lh(Vert4::point(0., 0., 0.), Vert4::point(0., 0., 1.), Vert4::vector(0., 1., 0.));
+    assert_eq!(default, Matr4::identity());
+
+    let from = || Vert4::point(1., 3., 2.);
+    let to = || Vert4::point(4., -2., 8.);
+    let up = || Vert4::vector(1., 1., 0.);
+    let rh = Matr4::view_transform(from(), to(), up());
+    let lh = Matr4::view_transform_lh(from(), to(), up());
+    // Same camera, with x and z flipped
+    let rows = |m: &Matr4| m.as_row_vectors().map(|row| Vert4::vector(row[0], row[1], row[2]));
+    let ([rh_x, rh_y, rh_z, _], [lh_x, lh_y, lh_z, _]) = (rows(&rh), rows(&lh));
+    assert_eq!(lh_x, -rh_x);
+    assert_eq!(lh_y, rh_y);
+    assert_eq!(lh_z, -rh_z);
+    assert_eq!(lh, Matr4::scaling(-1., 1., -1.) * rh);
+}
+//
+// Projection tests
+//
+// Depth in normalized device coordinates of the point at view space depth `z`
+fn ndc_depth(projection: &Matr4, z: f32) -> f32 {
+    (projection * Vert4::point(0., 0., z)).perspective_divide().z()
+}
+#[test]
+fn perspective_projection_depth_ranges() {
+    use core::f32::consts::FRAC_PI_2;
+
+    let gl = Matr4::perspective(FRAC_PI_2, 1.5, 0.1, 100.);
+    assert!((ndc_depth(&gl, -0.1) + 1.).abs() < 1e-4);
+    assert!((ndc_depth(&gl, -100.) - 1.).abs() < 1e-4);
+    let zo = Matr4::perspective_zo(FRAC_PI_2, 1.5, 0.1, 100.);
+    assert!(ndc_depth(&zo, -0.1).abs() < 1e-4);
+    assert!((ndc_depth(&zo, -100.) - 1.).abs() < 1e-4);
+    let reversed = Matr4::perspective_reversed_z(FRAC_PI_2, 1.5, 0.1, 100.);
+    assert!((ndc_depth(&reversed, -0.1) - 1.).abs() < 1e-4);
+    assert!(ndc_depth(&reversed, -100.).abs() < 1e-4);
+}
+#[test]
+fn perspective_projection_maps_the_frustum_edges() {
+    use core::f32::consts::FRAC_PI_2;
+
+    // With a 90° field of view the top edge is as far up as it is deep
+    let projection = Matr4::perspective_zo(FRAC_PI_2, 2., 1., 10.);
+    let corner = (&projection * Vert4::point(-10., 5., -5.)).perspective_divide();
+    assert_eq!(corner, Vert4::point(-1., 1., 0.888888));
+}
+#[test]
+fn infinite_perspective_projections() {
+    use core::f32::consts::FRAC_PI_3;
+
+    let gl = Matr4::perspective_infinite(FRAC_PI_3, 1., 0.5);
+    assert!((ndc_depth(&gl, -0.5) + 1.).abs() < 1e-4);
+    assert!((ndc_depth(&gl, -1e6) - 1.).abs() < 1e-4);
+    let zo = Matr4::perspective_infinite_zo(FRAC_PI_3, 1., 0.5);
+    assert!(ndc_depth(&zo, -0.5).abs() < 1e-4);
+    assert!((ndc_depth(&zo, -1e6) - 1.).abs() < 1e-4);
+    let reversed = Matr4::perspective_infinite_reversed_z(FRAC_PI_3, 1., 0.5);
+    assert!((ndc_depth(&reversed, -0.5) - 1.).abs() < 1e-4);
+    assert!(ndc_depth(&reversed, -1e6).abs() < 1e-4);
+    // The far limit of the finite projection
+    assert_eq!(Matr4::perspective_zo(FRAC_PI_3, 1., 0.5, 1e7), zo);
+}
+#[test]
+fn orthographic_projections_map_the_box_onto_the_unit_cube() {
+    let gl = Matr4::orthographic(-4., 2., -1., 3., 1., 11.);
+    assert_eq!(&gl * Vert4::point(-4., -1., -1.), Vert4::point(-1., -1., -1.));
+    assert_eq!(&gl * Vert4::point(2., 3., -11.), Vert4::point(1., 1., 1.));
+    assert_eq!(&gl * Vert4::vector(3., 2., -5.), Vert4::vector(1., 1., 1.));
+    let zo = Matr4::orthographic_zo(-4., 2., -1., 3., 1., 11.);
+    assert_eq!(&zo * Vert4::point(-4., -1., -1.), Vert4::point(-1., -1., 0.));
+    assert_eq!(&zo * Vert4::point(-1., 1., -6.), Vert4::point(0., 0., 0.5));
+}
+#[test]
+fn the_perspective_divide_gives_a_point() {
+    assert_eq!(Vert4::new(2., -4., 1., 2.).perspective_divide(), Vert4::point(1., -2., 0.5));
+}
+//
+// Decomposition tests
+//
+#[test]
+fn decomposing_a_product_of_the_builders() {
+    use geometry::Quat;
+
+    let transform = Matr4::translation(5., -3., 2.)
+        * Matr4::rotation_axis_angle(Vert4::vector(1., 2., 3.), 0.8)
+        * Matr4::scaling(2., 0.5, 3.)
+        * Matr4::shearing(
+            ShearingProportion::new(1., -2.),
+            ShearingProportion::new(0., 0.5),
+            ShearingProportion::default(),
+        );
+    let parts = transform.decompose().unwrap();
+    assert_eq!(parts.translation, Vert4::point(5., -3., 2.));
+    assert_eq!(parts.rotation, Quat::from_axis_angle_rad(&Vert4::vector(1., 2., 3.), 0.8));
+    assert_eq!(parts.scale, Vert4::vector(2., 0.5, 3.));
+    assert!((parts.shear_x.to_primary() - 1.).abs() < 1e-4);
+    assert!((parts.shear_x.to_secondary() + 2.).abs() < 1e-4);
+    assert!((parts.shear_y.to_secondary() - 0.5).abs() < 1e-4);
+    assert_eq!(Matr4::compose(&parts), transform);
+}
+#[test]
+fn decomposing_moves_reflections_into_the_scale() {
+    let transform = Matr4::rotation_y_deg(30.) * Matr4::scaling(1., 2., -1.);
+    let parts = transform.decompose().unwrap();
+    assert_eq!(Matr4::from(&parts.rotation), Matr4::rotation_y_deg(30.));
+    assert_eq!(parts.scale, Vert4::vector(1., 2., -1.));
+    assert_eq!(Matr4::compose(&parts), transform);
+}
+#[test]
+fn composing_round_trips_general_affine_transforms() {
+    let transforms = [
+        Matr4::identity(),
+        Matr4::rotation_z_rad(2.) * Matr4::translation(1., 2., 3.) * Matr4::scaling(-1., -1., 2.),
+        Matr4::shearing(
+            ShearingProportion::new(0.2, 0.),
+            ShearingProportion::new(0.3, 0.),
+            ShearingProportion::new(0.4, 0.5),
+        ) * Matr4::rotation_x_deg(-70.),
+        Matr4::view_transform(Vert4::point(1., 3., 2.), Vert4::point(4., -2., 8.), Vert4::vector(1., 1., 0.)),
+    ];
+    for transform in transforms {
+        assert_eq!(Matr4::compose(&transform.decompose().unwrap()), transform);
+    }
+}
+#[test]
+fn decomposing_keeps_small_scales() {
+    let cases = [
+        (Matr4::scaling(1e-5, 1., 1.), [1e-5, 1., 1.]),
+        (Matr4::rotation_x_deg(30.) * Matr4::scaling(1e-6, 2e-6, 3e-6), [1e-6, 2e-6, 3e-6]),
+    ];
+    for (transform, expected) in cases {
+        let parts = transform.decompose().unwrap();
+        let scale = [parts.scale.x(), parts.scale.y(), parts.scale.z()];
+        for (scale, expected) in scale.into_iter().zip(expected) {
+            assert!((scale - expected).abs() < expected * 1e-4);
+        }
+        assert_eq!(Matr4::compose(&parts), transform);
+    }
+}
+#[test]
+fn singular_and_projective_matrices_do_not_decompose() {
+    assert!(Matr4::scaling(1., 0., 1.).decompose().is_none());
+    assert!(Matr4::perspective(1., 1., 0.1, 10.).decompose().is_none());
+}
@@ -0,0 +1,208 @@
+use core::f32::consts::FRAC_PI_2;
+
+use geometry::pattern::{Blend, Checker2d, Checker3d, Gradient, Ring, Solid, Stripe};
+use geometry::shapes::{Group, Plane, Shape, Sphere};
+use geometry::{Color, Material, Matr4, Pattern, PointLight, Ray, Transform, Vert4, World, lighting, lighting_object};
+
+const WHITE: Color = Color::WHITE;
+const BLACK: Color = Color::BLACK;
+const RED: Color = Color::new(1., 0., 0.);
+const GREEN: Color = Color::new(0., 1., 0.);
+
+fn color_at(pattern: &dyn Pattern, x: f32, y: f32, z: f32) -> Color {
+    pattern.color_at(&Vert4::point(x, y, z))
+}
+//
+// Stripe tests
+//
+#[test]
+fn creating_a_stripe_pattern() {
+    let pattern = Stripe::new(WHITE, BLACK);
+    assert_eq!(color_at(pattern.a(), 0., 0., 0.), WHITE);
+    assert_eq!(color_at(pattern.b(), 0., 0., 0.), BLACK);
+    assert_eq!(pattern.transform(), &Transform::identity());
+}
+#[test]
+fn a_stripe_pattern_is_constant_in_y_and_z() {
+    let pattern = Stripe::new(WHITE, BLACK);
+    for (y, z) in [(0., 0.), (1., 0.), (2., 0.), (0., 1.), (0., 2.)] {
+        assert_eq!(color_at(&pattern, 0., y, z), WHITE);
+    }
+}
+#[test]
+fn a_stripe_pattern_alternates_in_x() {
+    let pattern = Stripe::new(WHITE, BLACK);
+    for (x, expected) in [(0., WHITE), (0.9, WHITE), (1., BLACK), (-0.1, BLACK), (-1., BLACK), (-1.1, WHITE)] {
+        assert_eq!(color_at(&pattern, x, 0., 0.), expected, "at x = {x}");
+    }
+}
+//
+// Transform tests
+//
+#[test]
+fn stripes_with_an_object_transformation() {
+    let object = Sphere::new().with_transform(Transform::identity().scale(2., 2., 2.));
+    let pattern = Stripe::new(WHITE, BLACK);
+    assert_eq!((&pattern as &dyn Pattern).color_at_object(&object, &Vert4::point(1.5, 0., 0.)), WHITE);
+}
+#[test]
+fn stripes_with_a_pattern_transformation() {
+    let object = Sphere::new();
+    let pattern = Stripe::new(WHITE, BLACK).with_transform(Transform::identity().scale(2., 2., 2.));
+    assert_eq!((&pattern as &dyn Pattern).color_at_object(&object, &Vert4::point(1.5, 0., 0.)), WHITE);
+}
+#[test]
+fn stripes_with_both_an_object_and_a_pattern_transformation() {
+    let object = Sphere::new().with_transform(Transform::identity().scale(2., 2., 2.));
+    let pattern = Stripe::new(WHITE, BLACK).with_transform(Transform::identity().translate(0.5, 0., 0.));
+    assert_eq!((&pattern as &dyn Pattern).color_at_object(&object, &Vert4::point(2.5, 0., 0.)), WHITE);
+}
+#[test]
+fn stripes_on_an_object_in_a_group() {
+    let sphere = Sphere::new().with_transform(Transform::identity().translate(1., 0., 0.));
+    let g = Group::new().with_child(sphere).with_transform(Transform::identity().scale(2., 2., 2.));
+    let object = g.children()[0].as_ref();
+    let pattern = Stripe::new(WHITE, BLACK);
+    // Object space x = x / 2 - 1
+    assert_eq!((&pattern as &dyn Pattern).color_at_object(object, &Vert4::point(2.5, 0., 0.)), WHITE);
+    assert_eq!((&pattern as &dyn Pattern).color_at_object(object, &Vert4::point(1.5, 0., 0.)), BLACK);
+}
+#[test]
+fn a_pattern_transformation_built_from_a_matrix() {
+    let matrix = Matr4::translation(0.5, 0., 0.) * Matr4::scaling(2., 2., 2.);
+    let mut pattern = Stripe::new(WHITE, BLACK);
+    pattern.set_transform(Transform::try_from(matrix).unwrap());
+    // Pattern space x = (x - 0.5) / 2
+    assert_eq!(color_at(&pattern, 2.4, 0., 0.), WHITE);
+    assert_eq!(color_at(&pattern, 2.6, 0., 0.), BLACK);
+    assert_eq!(color_at(&pattern, 0.4, 0., 0.), BLACK);
+}
+//
+// Gradient tests
+//
+#[test]
+fn a_gradient_linearly_interpolates_between_colors() {
+    let pattern = Gradient::new(WHITE, BLACK);
+    assert_eq!(color_at(&pattern, 0., 0., 0.), WHITE);
+    assert_eq!(color_at(&pattern, 0.25, 0., 0.), Color::new(0.75, 0.75, 0.75));
+    assert_eq!(color_at(&pattern, 0.5, 0., 0.), Color::new(0.5, 0.5, 0.5));
+    assert_eq!(color_at(&pattern, 0.75, 0., 0.), Color::new(0.25, 0.25, 0.25));
+    assert_eq!(color_at(&pattern, 1.25, 1., 1.), Color::new(0.75, 0.75, 0.75));
+}
+//
+// Ring tests
+//
+#[test]
+fn a_ring_should_extend_in_both_x_and_z() {
+    let pattern = Ring::new(WHITE, BLACK);
+    assert_eq!(color_at(&pattern, 0., 0., 0.), WHITE);
+    assert_eq!(color_at(&pattern, 1., 0., 0.), BLACK);
+    assert_eq!(color_at(&pattern, 0., 0., 1.), BLACK);
+    // Just slightly more than √2/2
+    assert_eq!(color_at(&pattern, 0.708, 0., 0.708), BLACK);
+    assert_eq!(color_at(&pattern, 0., 5., 2.5), WHITE);
+}
+//
+// Checker tests
+//
+#[test]
+fn checkers_should_repeat_in_x_y_and_z() {
+    let pattern = Checker3d::new(WHITE, BLACK);
+    for (x, y, z, expected) in [
+        (0., 0., 0., WHITE),
+        (0.99, 0., 0., WHITE),
+        (1.01, 0., 0., BLACK),
+        (0., 0.99, 0., WHITE),
+        (0., 1.01, 0., BLACK),
+        (0., 0., 0.99, WHITE),
+        (0., 0., 1.01, BLACK),
+        (-0.5, -0.5, -0.5, BLACK),
+        (1.5, 1.5, 0.5, WHITE),
+    ] {
+        assert_eq!(color_at(&pattern, x, y, z), expected, "at ({x}, {y}, {z})");
+    }
+}
+#[test]
+fn planar_checkers_ignore_y() {
+    let pattern = Checker2d::new(WHITE, BLACK);
+    for y in [-1.5, -0.01, 0., 0.01, 0.99, 1.01] {
+        assert_eq!(color_at(&pattern, 0.5, y, 0.5), WHITE);
+        assert_eq!(color_at(&pattern, 1.5, y, 0.5), BLACK);
+        assert_eq!(color_at(&pattern, 0.5, y, -0.5), BLACK);
+        assert_eq!(color_at(&pattern, -0.5, y, -0.5), WHITE);
+    }
+}
+//
+// Nesting tests
+//
+#[test]
+fn a_solid_pattern_is_the_same_everywhere() {
+    let pattern = Solid::new(Color::new(0.2, 0.4, 0.6)).with_transform(Transform::identity().scale(3., 3., 3.));
+    assert_eq!(pattern.color(), &Color::new(0.2, 0.4, 0.6));
+    assert_eq!(color_at(&pattern, -7., 0.3, 12.), Color::new(0.2, 0.4, 0.6));
+}
+#[test]
+fn nested_patterns_apply_their_own_transforms() {
+    // Stripes along x in the white squares, along z in the black ones
+    let along_x = Stripe::new(WHITE, RED).with_transform(Transform::identity().scale(0.25, 1., 1.));
+    let along_z = Stripe::new(BLACK, GREEN).with_transform(Transform::identity().scale(0.25, 1., 1.).rotate_y(FRAC_PI_2));
+    let pattern = Checker2d::nested(along_x, along_z);
+    assert_eq!(color_at(&pattern, 0.1, 0., 0.1), WHITE);
+    assert_eq!(color_at(&pattern, 0.3, 0., 0.1), RED);
+    assert_eq!(color_at(&pattern, 0.6, 0., 0.6), WHITE);
+    assert_eq!(color_at(&pattern, 1.1, 0., 0.1), GREEN);
+    assert_eq!(color_at(&pattern, 1.3, 0., 0.1), GREEN);
+    assert_eq!(color_at(&pattern, 1.1, 0., 0.3), BLACK);
+}
+#[test]
+fn nested_patterns_see_their_parents_pattern_space() {
+    // The stripes are scaled up with the rings
+    let pattern = Ring::nested(Stripe::new(WHITE, BLACK), Solid::new(BLACK)).with_transform(Transform::identity().scale(4., 4., 4.));
+    assert_eq!(color_at(&pattern, 1.5, 0., 0.), WHITE);
+    assert_eq!(color_at(&pattern, 3.5, 0., 0.), WHITE);
+    assert_eq!(color_at(&pattern, -1.5, 0., 0.), BLACK);
+    assert_eq!(color_at(&pattern, 4.5, 0., 0.), BLACK);
+}
+#[test]
+fn blending_mixes_the_patterns() {
+    let across = Stripe::new(WHITE, BLACK).with_transform(Transform::identity().rotate_y(FRAC_PI_2));
+    let pattern = Blend::new(Stripe::new(WHITE, BLACK), across);
+    assert_eq!(pattern.weight(), 0.5);
+    assert_eq!(color_at(&pattern, 0.5, 0., -0.5), WHITE);
+    assert_eq!(color_at(&pattern, 1.5, 0., -0.5), Color::new(0.5, 0.5, 0.5));
+    assert_eq!(color_at(&pattern, 0.5, 0., 0.5), Color::new(0.5, 0.5, 0.5));
+    assert_eq!(color_at(&pattern, 1.5, 0., 0.5), BLACK);
+}
+#[test]
+fn weighted_blending() {
+    let pattern = Blend::weighted(Solid::new(WHITE), Gradient::new(BLACK, WHITE), 0.25);
+    assert_eq!(color_at(&pattern, 0., 0., 0.), Color::new(0.75, 0.75, 0.75));
+    assert_eq!(color_at(&pattern, 0.5, 0., 0.), Color::new(0.875, 0.875, 0.875));
+}
+//
+// Lighting tests
+//
+fn flat_material(pattern: impl Pattern + 'static) -> Material {
+    Material { pattern: Some(Box::new(pattern)), ambient: 1., diffuse: 0., specular: 0., ..Material::default() }
+}
+#[test]
+fn lighting_with_a_pattern_applied() {
+    let object = Sphere::new().with_material(flat_material(Stripe::new(WHITE, BLACK)));
+    let eyev = Vert4::vector(0., 0., -1.);
+    let normalv = Vert4::vector(0., 0., -1.);
+    let light = PointLight::new(Vert4::point(0., 0., -10.), WHITE);
+    let c1 = lighting_object(&object, &light, &Vert4::point(0.9, 0., 0.), &eyev, &normalv, false);
+    let c2 = lighting_object(&object, &light, &Vert4::point(1.1, 0., 0.), &eyev, &normalv, false);
+    assert_eq!((c1, c2), (WHITE, BLACK));
+    // Without the object the pattern cannot be placed
+    let plain = lighting(object.material(), &light, &Vert4::point(1.1, 0., 0.), &eyev, &normalv, false);
+    assert_eq!(plain, WHITE);
+}
+#[test]
+fn a_world_shades_with_patterns() {
+    let mut w = World::new();
+    w.add_light(PointLight::new(Vert4::point(0., 10., 0.), WHITE));
+    w.add_object(Plane::new().with_material(flat_material(Checker2d::new(WHITE, BLACK))));
+    assert_eq!(w.color_at(&Ray::new(Vert4::point(0.5, 1., 0.5), Vert4::vector(0., -1., 0.))), WHITE);
+    assert_eq!(w.color_at(&Ray::new(Vert4::point(1.5, 1., 0.5), Vert4::vector(0., -1., 0.))), BLACK);
+}
@@ -0,0 +1,90 @@
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use geometry::matrix::Inverse;
+use geometry::{point, vector, vux, vuy, vuz};
+use geometry::{DMatr4, DQuat, Mag, Matr3, Matr4, Matrix, Norm, Quat, Vert4};
+
+#[test]
+fn axis_angle_matches_the_axis_rotation_matrices() {
+    let angle = 0.7;
+    assert_eq!(Matr4::from(Quat::from_axis_angle_rad(&vux!(), angle)), Matr4::rotation_x_rad(angle));
+    assert_eq!(Matr4::from(Quat::from_axis_angle_rad(&vuy!(), angle)), Matr4::rotation_y_rad(angle));
+    assert_eq!(Matr4::from(Quat::from_axis_angle_rad(&vuz!(), angle)), Matr4::rotation_z_rad(angle));
+    assert_eq!(Quat::from_axis_angle_deg(&vector!(0, 0, 3), 90.), Quat::from_axis_angle_rad(&vuz!(), FRAC_PI_2));
+}
+#[test]
+fn rotating_a_point_and_a_vector() {
+    let half_quarter = Quat::from_axis_angle_rad(&vux!(), FRAC_PI_4);
+    let full_quarter = Quat::from_axis_angle_rad(&vux!(), FRAC_PI_2);
+    let p = point!(0, 1, 0);
+    assert_eq!(&half_quarter * p, point!(0, 2_f32.sqrt() / 2., 2_f32.sqrt() / 2.));
+    assert_eq!(&full_quarter * p, point!(0, 0, 1));
+    assert_eq!(full_quarter * vector!(1, 1, 0), vector!(1, 0, 1));
+}
+#[test]
+fn rotating_matches_the_matrix() {
+    let q = Quat::from_axis_angle_rad(&vector!(1, -2, 0.5), 1.3);
+    let v = Vert4::new(0.3, -4., 2.5, 1.);
+    assert_eq!(&q * v, Matr4::from(&q) * v);
+}
+#[test]
+fn hamilton_product_composes_like_the_matrices() {
+    let a = Quat::from_axis_angle_rad(&vux!(), 0.4);
+    let b = Quat::from_axis_angle_rad(&vuy!(), -1.1);
+    assert_eq!(Matr4::from(&a * &b), Matr4::from(&a) * Matr4::from(&b));
+    let mut c = a;
+    c *= Quat::IDENTITY;
+    assert_eq!(Matr4::from(c), Matr4::rotation_x_rad(0.4));
+}
+#[test]
+fn conjugate_and_inverse() {
+    let q = Quat::new(1., 2., 3., 4.);
+    assert_eq!(q.conjugate(), Quat::new(-1., -2., -3., 4.));
+    assert_eq!(&q * q.inverse().unwrap(), Quat::IDENTITY);
+    assert_eq!(Quat::new(0., 0., 0., 0.).inverse(), None);
+    let unit = Quat::from_axis_angle_rad(&vuz!(), 0.9);
+    assert_eq!(unit.inverse().unwrap(), unit.conjugate());
+}
+#[test]
+fn normalizing() {
+    let q = Quat::new(1., 2., 3., 4.);
+    assert_eq!(q.mag(), 30_f32.sqrt());
+    assert_eq!(q.norm(), &q / 30_f32.sqrt());
+    assert_eq!(&q * 2., Quat::new(2., 4., 6., 8.));
+    assert_eq!(-q, Quat::new(-1., -2., -3., -4.));
+}
+#[test]
+fn interpolating() {
+    let from = Quat::IDENTITY;
+    let to = Quat::from_axis_angle_rad(&vuy!(), FRAC_PI_2);
+    let halfway = Quat::from_axis_angle_rad(&vuy!(), FRAC_PI_4);
+    assert_eq!(from.slerp(&to, 0.5), halfway);
+    assert_eq!(from.nlerp(&to, 0.5), halfway);
+    assert_eq!(from.slerp(&to, 0.), from);
+    assert_eq!(from.slerp(&to, 1.), to);
+    // -to is the same rotation, so it takes the same short path
+    assert_eq!(from.slerp(&-&to, 0.5), halfway);
+    assert_eq!(to.slerp(&to, 0.3), to);
+}
+#[test]
+fn converting_from_matrices() {
+    let rotations = [
+        Matr4::identity(),
+        Matr4::rotation_x_rad(PI),
+        Matr4::rotation_y_rad(PI),
+        Matr4::rotation_z_rad(PI),
+        Matr4::rotation_z_rad(0.5) * Matr4::rotation_x_rad(-2.) * Matr4::rotation_y_rad(1.2),
+    ];
+    for rotation in rotations {
+        assert_eq!(Matr4::from(Quat::from(&rotation)), rotation);
+    }
+    let with_translation = Matr4::translation(1., 2., 3.) * Matr4::rotation_y_rad(0.6);
+    assert_eq!(Quat::from(&with_translation), Quat::from_axis_angle_rad(&vuy!(), 0.6));
+    let q = Quat::from_axis_angle_rad(&vector!(2, 1, -1), 2.2);
+    assert_eq!(Quat::from(&Matr3::from(&q)), q);
+}
+#[test]
+fn double_precision() {
+    let q = DQuat::from_axis_angle_rad(&vector!(f64; 0, 1, 0), core::f64::consts::FRAC_PI_2);
+    assert_eq!(&q * point!(f64; 1, 0, 0), point!(f64; 0, 0, -1));
+    assert_eq!(DMatr4::from(&q), DMatr4::rotation_y_rad(core::f64::consts::FRAC_PI_2));
+}
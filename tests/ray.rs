@@ -0,0 +1,60 @@
+use geometry::ray::InvalidRay;
+use geometry::{Matr4, Ray, Vert4};
+//
+// Construction tests
+//
+#[test]
+fn creating_and_querying_a_ray() {
+    let origin = Vert4::point(1., 2., 3.);
+    let direction = Vert4::vector(4., 5., 6.);
+
+    let r = Ray::new(origin, direction);
+    assert_eq!(r.origin, Vert4::point(1., 2., 3.));
+    assert_eq!(r.direction, Vert4::vector(4., 5., 6.));
+}
+#[test]
+fn computing_a_point_from_a_distance() {
+    let r = Ray::new(Vert4::point(2., 3., 4.), Vert4::vector(1., 0., 0.));
+
+    assert_eq!(r.position(0.), Vert4::point(2., 3., 4.));
+    assert_eq!(r.position(1.), Vert4::point(3., 3., 4.));
+    assert_eq!(r.position(-1.), Vert4::point(1., 3., 4.));
+    assert_eq!(r.position(2.5), Vert4::point(4.5, 3., 4.));
+}
+//
+// Transformation tests
+//
+#[test]
+fn translating_a_ray() {
+    let r = Ray::new(Vert4::point(1., 2., 3.), Vert4::vector(0., 1., 0.));
+    let m = Matr4::translation(3., 4., 5.);
+
+    let r2 = r.transform(&m).unwrap();
+    assert_eq!(r2.origin, Vert4::point(4., 6., 8.));
+    assert_eq!(r2.direction, Vert4::vector(0., 1., 0.));
+}
+#[test]
+fn scaling_a_ray() {
+    let r = Ray::new(Vert4::point(1., 2., 3.), Vert4::vector(0., 1., 0.));
+    let m = Matr4::scaling(2., 3., 4.);
+
+    let r2 = r.transform(&m).unwrap();
+    assert_eq!(r2.origin, Vert4::point(2., 6., 12.));
+    assert_eq!(r2.direction, Vert4::vector(0., 3., 0.));
+}
+#[test]
+fn transforming_leaves_the_original_ray() {
+    let r = Ray::new(Vert4::point(1., 2., 3.), Vert4::vector(0., 1., 0.));
+    let _ = r.transform(&Matr4::rotation_x_rad(1.)).unwrap();
+    assert_eq!(r, Ray::new(Vert4::point(1., 2., 3.), Vert4::vector(0., 1., 0.)));
+}
+#[test]
+fn transforming_rejects_a_vector_origin() {
+    let r = Ray::new(Vert4::vector(1., 2., 3.), Vert4::vector(0., 1., 0.));
+    assert_eq!(r.transform(&Matr4::translation(3., 4., 5.)), Err(InvalidRay::OriginNotAPoint));
+}
+#[test]
+fn transforming_rejects_a_point_direction() {
+    let r = Ray::new(Vert4::point(1., 2., 3.), Vert4::point(0., 1., 0.));
+    assert_eq!(r.transform(&Matr4::translation(3., 4., 5.)), Err(InvalidRay::DirectionNotAVector));
+}
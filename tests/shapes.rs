@@ -0,0 +1,707 @@
+use core::f32::consts::{FRAC_1_SQRT_2, PI};
+
+use geometry::shapes::{barycentric, Cone, Cube, Cylinder, Intersection, Intersections, Plane, Shape, SmoothTriangle, Sphere, Triangle};
+use geometry::shapes::{Group, ParentChain};
+use geometry::ray::InvalidRay;
+use geometry::{Aabb, Material, Ray, Transform, Vert4};
+
+fn approx(l: f32, r: f32) -> bool {
+    (l - r).abs() < 1e-4
+}
+fn ts(xs: &Intersections) -> Vec<f32> {
+    xs.iter().map(|i| i.t).collect()
+}
+//
+// Intersection tests
+//
+mod intersections {
+    use super::*;
+
+    #[test]
+    fn aggregating_intersections_sorts_them() {
+        let s = Sphere::new();
+        let xs = Intersections::new(vec![Intersection::new(2., &s), Intersection::new(-1., &s)]);
+        assert_eq!(ts(&xs), vec![-1., 2.]);
+    }
+    #[test]
+    fn the_hit_when_all_intersections_have_positive_t() {
+        let s = Sphere::new();
+        let xs = Intersections::new(vec![Intersection::new(1., &s), Intersection::new(2., &s)]);
+        assert_eq!(xs.hit(), Some(&Intersection::new(1., &s)));
+    }
+    #[test]
+    fn the_hit_when_some_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let xs = Intersections::new(vec![Intersection::new(-1., &s), Intersection::new(1., &s)]);
+        assert_eq!(xs.hit(), Some(&Intersection::new(1., &s)));
+    }
+    #[test]
+    fn the_hit_when_all_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let xs = Intersections::new(vec![Intersection::new(-2., &s), Intersection::new(-1., &s)]);
+        assert_eq!(xs.hit(), None);
+    }
+    #[test]
+    fn the_hit_is_always_the_lowest_nonnegative_intersection() {
+        let s = Sphere::new();
+        let xs: Intersections = [5., 7., -3., 2.].into_iter().map(|t| Intersection::new(t, &s)).collect();
+        assert_eq!(xs.hit(), Some(&Intersection::new(2., &s)));
+    }
+    #[test]
+    fn intersections_on_different_objects_differ() {
+        let (s1, s2) = (Sphere::new(), Sphere::new());
+        assert_ne!(Intersection::new(1., &s1), Intersection::new(1., &s2));
+    }
+}
+//
+// Sphere tests
+//
+mod sphere {
+    use super::*;
+
+    #[test]
+    fn a_ray_intersects_a_sphere_at_two_points() {
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new();
+        assert_eq!(ts(&(&s as &dyn Shape).intersect(&r).unwrap()), vec![4., 6.]);
+    }
+    #[test]
+    fn a_ray_intersects_a_sphere_at_a_tangent() {
+        let r = Ray::new(Vert4::point(0., 1., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new();
+        assert_eq!(ts(&(&s as &dyn Shape).intersect(&r).unwrap()), vec![5., 5.]);
+    }
+    #[test]
+    fn a_ray_misses_a_sphere() {
+        let r = Ray::new(Vert4::point(0., 2., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new();
+        assert!((&s as &dyn Shape).intersect(&r).unwrap().is_empty());
+    }
+    #[test]
+    fn a_ray_originates_inside_a_sphere() {
+        let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new();
+        assert_eq!(ts(&(&s as &dyn Shape).intersect(&r).unwrap()), vec![-1., 1.]);
+    }
+    #[test]
+    fn intersect_sets_the_object() {
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new();
+        let shape: &dyn Shape = &s;
+        let xs = shape.intersect(&r).unwrap();
+        assert!(xs.iter().all(|i| core::ptr::addr_eq(i.object, shape)));
+    }
+    #[test]
+    fn intersecting_with_a_malformed_ray_fails() {
+        let s = Sphere::new();
+        let r = Ray::new(Vert4::vector(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        assert_eq!((&s as &dyn Shape).intersect(&r), Err(InvalidRay::OriginNotAPoint));
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::point(0., 0., 1.));
+        assert_eq!((&s as &dyn Shape).intersect(&r), Err(InvalidRay::DirectionNotAVector));
+    }
+    #[test]
+    fn a_sphere_has_the_identity_transform_by_default() {
+        assert_eq!(Sphere::new().transform(), &Transform::identity());
+    }
+    #[test]
+    fn intersecting_a_scaled_sphere_with_a_ray() {
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new().with_transform(Transform::identity().scale(2., 2., 2.));
+        assert_eq!(ts(&(&s as &dyn Shape).intersect(&r).unwrap()), vec![3., 7.]);
+    }
+    #[test]
+    fn intersecting_a_translated_sphere_with_a_ray() {
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        let s = Sphere::new().with_transform(Transform::identity().translate(5., 0., 0.));
+        assert!((&s as &dyn Shape).intersect(&r).unwrap().is_empty());
+    }
+    #[test]
+    fn the_normal_on_a_sphere_is_normalized() {
+        let s = Sphere::new();
+        let k = 3f32.sqrt() / 3.;
+        let n = (&s as &dyn Shape).normal_at(&Vert4::point(k, k, k)).unwrap();
+        assert_eq!(n, Vert4::vector(k, k, k));
+    }
+    #[test]
+    fn the_normal_on_a_sphere_at_points_on_the_axes() {
+        let s: &dyn Shape = &Sphere::new();
+        assert_eq!(s.normal_at(&Vert4::point(1., 0., 0.)).unwrap(), Vert4::vector(1., 0., 0.));
+        assert_eq!(s.normal_at(&Vert4::point(0., 1., 0.)).unwrap(), Vert4::vector(0., 1., 0.));
+        assert_eq!(s.normal_at(&Vert4::point(0., 0., 1.)).unwrap(), Vert4::vector(0., 0., 1.));
+    }
+    #[test]
+    fn computing_the_normal_on_a_translated_sphere() {
+        let s = Sphere::new().with_transform(Transform::identity().translate(0., 1., 0.));
+        let n = (&s as &dyn Shape).normal_at(&Vert4::point(0., 1. + FRAC_1_SQRT_2, -FRAC_1_SQRT_2)).unwrap();
+        assert_eq!(n, Vert4::vector(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    }
+    #[test]
+    fn computing_the_normal_on_a_transformed_sphere() {
+        let s = Sphere::new().with_transform(Transform::identity().rotate_z(PI / 5.).scale(1., 0.5, 1.));
+        let k = 2f32.sqrt() / 2.;
+        let n = (&s as &dyn Shape).normal_at(&Vert4::point(0., k, -k)).unwrap();
+        assert_eq!(n, Vert4::vector(0., 0.97014, -0.24254));
+    }
+}
+//
+// Plane tests
+//
+mod plane {
+    use super::*;
+
+    #[test]
+    fn the_normal_of_a_plane_is_constant_everywhere() {
+        let p = Plane::new();
+        for point in [Vert4::point(0., 0., 0.), Vert4::point(10., 0., -10.), Vert4::point(-5., 0., 150.)] {
+            assert_eq!(p.local_normal_at(&point).unwrap(), Vert4::vector(0., 1., 0.));
+        }
+    }
+    #[test]
+    fn intersect_with_a_ray_parallel_to_the_plane() {
+        let p = Plane::new();
+        let r = Ray::new(Vert4::point(0., 10., 0.), Vert4::vector(0., 0., 1.));
+        assert!(p.local_intersect(&r).is_empty());
+    }
+    #[test]
+    fn intersect_with_a_coplanar_ray() {
+        let p = Plane::new();
+        let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+        assert!(p.local_intersect(&r).is_empty());
+    }
+    #[test]
+    fn a_ray_intersecting_a_plane_from_above() {
+        let p = Plane::new();
+        let r = Ray::new(Vert4::point(0., 1., 0.), Vert4::vector(0., -1., 0.));
+        assert_eq!(ts(&p.local_intersect(&r)), vec![1.]);
+    }
+    #[test]
+    fn a_ray_intersecting_a_plane_from_below() {
+        let p = Plane::new();
+        let r = Ray::new(Vert4::point(0., -1., 0.), Vert4::vector(0., 1., 0.));
+        assert_eq!(ts(&p.local_intersect(&r)), vec![1.]);
+    }
+}
+//
+// Cube tests
+//
+mod cube {
+    use super::*;
+
+    #[test]
+    fn a_ray_intersects_a_cube() {
+        let c = Cube::new();
+        let cases = [
+            (Vert4::point(5., 0.5, 0.), Vert4::vector(-1., 0., 0.), 4., 6.),
+            (Vert4::point(-5., 0.5, 0.), Vert4::vector(1., 0., 0.), 4., 6.),
+            (Vert4::point(0.5, 5., 0.), Vert4::vector(0., -1., 0.), 4., 6.),
+            (Vert4::point(0.5, -5., 0.), Vert4::vector(0., 1., 0.), 4., 6.),
+            (Vert4::point(0.5, 0., 5.), Vert4::vector(0., 0., -1.), 4., 6.),
+            (Vert4::point(0.5, 0., -5.), Vert4::vector(0., 0., 1.), 4., 6.),
+            (Vert4::point(0., 0.5, 0.), Vert4::vector(0., 0., 1.), -1., 1.),
+        ];
+        for (origin, direction, t1, t2) in cases {
+            let xs = c.local_intersect(&Ray::new(origin, direction));
+            assert_eq!(ts(&xs), vec![t1, t2]);
+        }
+    }
+    #[test]
+    fn a_ray_misses_a_cube() {
+        let c = Cube::new();
+        let cases = [
+            (Vert4::point(-2., 0., 0.), Vert4::vector(0.2673, 0.5345, 0.8018)),
+            (Vert4::point(0., -2., 0.), Vert4::vector(0.8018, 0.2673, 0.5345)),
+            (Vert4::point(0., 0., -2.), Vert4::vector(0.5345, 0.8018, 0.2673)),
+            (Vert4::point(2., 0., 2.), Vert4::vector(0., 0., -1.)),
+            (Vert4::point(0., 2., 2.), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(2., 2., 0.), Vert4::vector(-1., 0., 0.)),
+        ];
+        for (origin, direction) in cases {
+            assert!(c.local_intersect(&Ray::new(origin, direction)).is_empty());
+        }
+    }
+    #[test]
+    fn the_normal_on_the_surface_of_a_cube() {
+        let c = Cube::new();
+        let cases = [
+            (Vert4::point(1., 0.5, -0.8), Vert4::vector(1., 0., 0.)),
+            (Vert4::point(-1., -0.2, 0.9), Vert4::vector(-1., 0., 0.)),
+            (Vert4::point(-0.4, 1., -0.1), Vert4::vector(0., 1., 0.)),
+            (Vert4::point(0.3, -1., -0.7), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(-0.6, 0.3, 1.), Vert4::vector(0., 0., 1.)),
+            (Vert4::point(0.4, 0.4, -1.), Vert4::vector(0., 0., -1.)),
+            (Vert4::point(1., 1., 1.), Vert4::vector(1., 0., 0.)),
+            (Vert4::point(-1., -1., -1.), Vert4::vector(-1., 0., 0.)),
+        ];
+        for (point, normal) in cases {
+            assert_eq!(c.local_normal_at(&point).unwrap(), normal);
+        }
+    }
+}
+//
+// Cylinder tests
+//
+mod cylinder {
+    use super::*;
+    use geometry::Norm;
+
+    #[test]
+    fn a_ray_misses_a_cylinder() {
+        let c = Cylinder::new();
+        let cases = [
+            (Vert4::point(1., 0., 0.), Vert4::vector(0., 1., 0.)),
+            (Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.)),
+            (Vert4::point(0., 0., -5.), Vert4::vector(1., 1., 1.)),
+        ];
+        for (origin, direction) in cases {
+            assert!(c.local_intersect(&Ray::new(origin, direction.norm())).is_empty());
+        }
+    }
+    #[test]
+    fn a_ray_strikes_a_cylinder() {
+        let c = Cylinder::new();
+        let cases = [
+            (Vert4::point(1., 0., -5.), Vert4::vector(0., 0., 1.), 5., 5.),
+            (Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.), 4., 6.),
+            (Vert4::point(0.5, 0., -5.), Vert4::vector(0.1, 1., 1.), 6.80798, 7.08872),
+        ];
+        for (origin, direction, t1, t2) in cases {
+            let xs = c.local_intersect(&Ray::new(origin, direction.norm()));
+            assert_eq!(xs.len(), 2);
+            assert!(approx(xs[0].t, t1) && approx(xs[1].t, t2), "{:?}", ts(&xs));
+        }
+    }
+    #[test]
+    fn normal_vector_on_a_cylinder() {
+        let c = Cylinder::new();
+        let cases = [
+            (Vert4::point(1., 0., 0.), Vert4::vector(1., 0., 0.)),
+            (Vert4::point(0., 5., -1.), Vert4::vector(0., 0., -1.)),
+            (Vert4::point(0., -2., 1.), Vert4::vector(0., 0., 1.)),
+            (Vert4::point(-1., 1., 0.), Vert4::vector(-1., 0., 0.)),
+        ];
+        for (point, normal) in cases {
+            assert_eq!(c.local_normal_at(&point).unwrap(), normal);
+        }
+    }
+    #[test]
+    fn the_default_cylinder_is_infinite_and_open() {
+        let c = Cylinder::new();
+        assert_eq!(c.minimum(), f32::NEG_INFINITY);
+        assert_eq!(c.maximum(), f32::INFINITY);
+        assert!(!c.is_closed());
+    }
+    #[test]
+    fn intersecting_a_constrained_cylinder() {
+        let c = Cylinder::open(1., 2.);
+        let cases = [
+            (Vert4::point(0., 1.5, 0.), Vert4::vector(0.1, 1., 0.), 0),
+            (Vert4::point(0., 3., -5.), Vert4::vector(0., 0., 1.), 0),
+            (Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.), 0),
+            (Vert4::point(0., 2., -5.), Vert4::vector(0., 0., 1.), 0),
+            (Vert4::point(0., 1., -5.), Vert4::vector(0., 0., 1.), 0),
+            (Vert4::point(0., 1.5, -2.), Vert4::vector(0., 0., 1.), 2),
+        ];
+        for (origin, direction, count) in cases {
+            assert_eq!(c.local_intersect(&Ray::new(origin, direction.norm())).len(), count);
+        }
+    }
+    #[test]
+    fn intersecting_the_caps_of_a_closed_cylinder() {
+        let c = Cylinder::capped(1., 2.);
+        assert!(c.is_closed());
+        let cases = [
+            (Vert4::point(0., 3., 0.), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(0., 3., -2.), Vert4::vector(0., -1., 2.)),
+            (Vert4::point(0., 4., -2.), Vert4::vector(0., -1., 1.)),
+            (Vert4::point(0., 0., -2.), Vert4::vector(0., 1., 2.)),
+            (Vert4::point(0., -1., -2.), Vert4::vector(0., 1., 1.)),
+        ];
+        for (origin, direction) in cases {
+            assert_eq!(c.local_intersect(&Ray::new(origin, direction.norm())).len(), 2);
+        }
+    }
+    #[test]
+    fn the_normal_on_the_caps_of_a_closed_cylinder() {
+        let c = Cylinder::capped(1., 2.);
+        let cases = [
+            (Vert4::point(0., 1., 0.), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(0.5, 1., 0.), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(0., 1., 0.5), Vert4::vector(0., -1., 0.)),
+            (Vert4::point(0., 2., 0.), Vert4::vector(0., 1., 0.)),
+            (Vert4::point(0.5, 2., 0.), Vert4::vector(0., 1., 0.)),
+            (Vert4::point(0., 2., 0.5), Vert4::vector(0., 1., 0.)),
+        ];
+        for (point, normal) in cases {
+            assert_eq!(c.local_normal_at(&point).unwrap(), normal);
+        }
+    }
+}
+//
+// Cone tests
+//
+mod cone {
+    use super::*;
+    use geometry::Norm;
+
+    #[test]
+    fn intersecting_a_cone_with_a_ray() {
+        let c = Cone::new();
+        let cases = [
+            (Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.), 5., 5.),
+            (Vert4::point(0., 0., -5.), Vert4::vector(1., 1., 1.), 8.66025, 8.66025),
+            (Vert4::point(1., 1., -5.), Vert4::vector(-0.5, -1., 1.), 4.55006, 49.44994),
+        ];
+        for (origin, direction, t1, t2) in cases {
+            let xs = c.local_intersect(&Ray::new(origin, direction.norm()));
+            assert_eq!(xs.len(), 2);
+            assert!(approx(xs[0].t, t1) && (xs[1].t - t2).abs() < 1e-2, "{:?}", ts(&xs));
+        }
+    }
+    #[test]
+    fn intersecting_a_cone_with_a_ray_parallel_to_one_of_its_halves() {
+        let c = Cone::new();
+        let r = Ray::new(Vert4::point(0., 0., -1.), Vert4::vector(0., 1., 1.).norm());
+        let xs = c.local_intersect(&r);
+        assert_eq!(xs.len(), 1);
+        assert!(approx(xs[0].t, 0.35355));
+    }
+    #[test]
+    fn intersecting_the_caps_of_a_closed_cone() {
+        let c = Cone::capped(-0.5, 0.5);
+        let cases = [
+            (Vert4::point(0., 0., -5.), Vert4::vector(0., 1., 0.), 0),
+            (Vert4::point(0., 0., -0.25), Vert4::vector(0., 1., 1.), 2),
+            (Vert4::point(0., 0., -0.25), Vert4::vector(0., 1., 0.), 4),
+        ];
+        for (origin, direction, count) in cases {
+            assert_eq!(c.local_intersect(&Ray::new(origin, direction.norm())).len(), count);
+        }
+    }
+    #[test]
+    fn computing_the_normal_vector_on_a_cone() {
+        let c = Cone::new();
+        let cases = [
+            (Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 0.)),
+            (Vert4::point(1., 1., 1.), Vert4::vector(1., -(2f32.sqrt()), 1.)),
+            (Vert4::point(-1., -1., 0.), Vert4::vector(-1., 1., 0.)),
+        ];
+        for (point, normal) in cases {
+            assert_eq!(c.local_normal_at(&point).unwrap(), normal);
+        }
+    }
+}
+//
+// Triangle tests
+//
+mod triangle {
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(Vert4::point(0., 1., 0.), Vert4::point(-1., 0., 0.), Vert4::point(1., 0., 0.))
+    }
+    #[test]
+    fn constructing_a_triangle() {
+        let t = triangle();
+        assert_eq!(t.e1(), &Vert4::vector(-1., -1., 0.));
+        assert_eq!(t.e2(), &Vert4::vector(1., -1., 0.));
+        assert_eq!(t.normal(), &Vert4::vector(0., 0., -1.));
+    }
+    #[test]
+    fn finding_the_normal_on_a_triangle() {
+        let t = triangle();
+        for point in [Vert4::point(0., 0.5, 0.), Vert4::point(-0.5, 0.75, 0.), Vert4::point(0.5, 0.25, 0.)] {
+            assert_eq!(t.local_normal_at(&point).unwrap(), *t.normal());
+        }
+    }
+    #[test]
+    fn intersecting_a_ray_parallel_to_the_triangle() {
+        let r = Ray::new(Vert4::point(0., -1., -2.), Vert4::vector(0., 1., 0.));
+        assert!(triangle().local_intersect(&r).is_empty());
+    }
+    #[test]
+    fn a_ray_misses_each_edge() {
+        let t = triangle();
+        for origin in [Vert4::point(1., 1., -2.), Vert4::point(-1., 1., -2.), Vert4::point(0., -1., -2.)] {
+            assert!(t.local_intersect(&Ray::new(origin, Vert4::vector(0., 0., 1.))).is_empty());
+        }
+    }
+    #[test]
+    fn a_ray_strikes_a_triangle() {
+        let t = triangle();
+        let r = Ray::new(Vert4::point(0., 0.5, -2.), Vert4::vector(0., 0., 1.));
+        let xs = t.local_intersect(&r);
+        assert_eq!(ts(&xs), vec![2.]);
+    }
+    #[test]
+    fn an_intersection_with_a_triangle_stores_u_and_v() {
+        let t = SmoothTriangle::new(
+            Vert4::point(0., 1., 0.),
+            Vert4::point(-1., 0., 0.),
+            Vert4::point(1., 0., 0.),
+            Vert4::vector(0., 1., 0.),
+            Vert4::vector(-1., 0., 0.),
+            Vert4::vector(1., 0., 0.),
+        );
+        let r = Ray::new(Vert4::point(-0.2, 0.3, -2.), Vert4::vector(0., 0., 1.));
+        let (u, v) = t.local_intersect(&r)[0].uv.unwrap();
+        assert!(approx(u, 0.45) && approx(v, 0.25));
+    }
+    #[test]
+    fn a_smooth_triangle_interpolates_the_normal() {
+        let t = SmoothTriangle::new(
+            Vert4::point(0., 1., 0.),
+            Vert4::point(-1., 0., 0.),
+            Vert4::point(1., 0., 0.),
+            Vert4::vector(0., 1., 0.),
+            Vert4::vector(-1., 0., 0.),
+            Vert4::vector(1., 0., 0.),
+        );
+        assert_eq!(t.normal_at_uv(0.45, 0.25), Vert4::vector(-0.2, 0.3, 0.));
+        let n = (&t as &dyn Shape).normal_at(&Vert4::point(-0.2, 0.3, 0.)).unwrap();
+        assert_eq!(n, Vert4::vector(-0.5547, 0.83205, 0.));
+    }
+    #[test]
+    fn barycentric_weights_reproduce_the_point() {
+        let (a, b, c) = (Vert4::point(0., 1., 0.), Vert4::point(-1., 0., 0.), Vert4::point(1., 0., 0.));
+        let (w, u, v) = barycentric(&Vert4::point(-0.2, 0.3, 0.), &a, &b, &c);
+        assert!(approx(w, 0.3) && approx(u, 0.45) && approx(v, 0.25));
+        assert!(approx(w + u + v, 1.));
+        assert_eq!(barycentric(&a, &a, &b, &c), (1., 0., 0.));
+    }
+}
+//
+// Bounds tests
+//
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn bounded_shapes_in_object_space() {
+        let unit = Aabb::new(&Vert4::point(-1., -1., -1.), &Vert4::point(1., 1., 1.));
+        assert_eq!(Sphere::new().bounds(), unit);
+        assert_eq!(Cube::new().bounds(), unit);
+        assert_eq!(Cylinder::capped(-5., 3.).bounds(), Aabb::new(&Vert4::point(-1., -5., -1.), &Vert4::point(1., 3., 1.)));
+        assert_eq!(Cone::capped(-5., 3.).bounds(), Aabb::new(&Vert4::point(-5., -5., -5.), &Vert4::point(5., 3., 5.)));
+        let t = Triangle::new(Vert4::point(-3., 7., 2.), Vert4::point(6., 2., -4.), Vert4::point(2., -1., -1.));
+        assert_eq!(t.bounds(), Aabb::new(&Vert4::point(-3., -1., -4.), &Vert4::point(6., 7., 2.)));
+    }
+    #[test]
+    fn unbounded_shapes_in_object_space() {
+        assert!(!Plane::new().bounds().is_finite());
+        assert!(!Cylinder::new().bounds().is_finite());
+        assert!(!Cone::new().bounds().is_finite());
+    }
+    #[test]
+    fn bounds_in_world_space() {
+        let s = Sphere::new().with_transform(Transform::identity().scale(0.5, 2., 4.).translate(1., -3., 5.));
+        let b = (&s as &dyn Shape).world_bounds();
+        assert_eq!(b, Aabb::new(&Vert4::point(0.5, -5., 1.), &Vert4::point(1.5, -1., 9.)));
+        let p = Plane::new().with_transform(Transform::identity().rotate_x(PI / 2.));
+        assert_eq!((&p as &dyn Shape).world_bounds(), Aabb::infinite());
+    }
+}
+//
+// Group tests
+//
+mod group {
+    use core::cell::Cell;
+    use core::f32::consts::FRAC_PI_2;
+    use core::ptr;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn is(object: &dyn Shape, expected: &dyn Shape) -> bool {
+        ptr::addr_eq(object, expected)
+    }
+    // A unit sphere counting the rays that reach it
+    #[derive(Debug)]
+    struct Counting {
+        sphere: Sphere,
+        parents: ParentChain,
+        rays: Rc<Cell<usize>>,
+    }
+    impl Counting {
+        fn new(rays: &Rc<Cell<usize>>) -> Counting {
+            Counting { sphere: Sphere::new(), parents: ParentChain::default(), rays: Rc::clone(rays) }
+        }
+    }
+    impl Shape for Counting {
+        fn transform(&self) -> &Transform {
+            self.sphere.transform()
+        }
+        fn set_transform(&mut self, transform: Transform) {
+            self.sphere.set_transform(transform)
+        }
+        fn material(&self) -> &Material {
+            self.sphere.material()
+        }
+        fn set_material(&mut self, material: Material) {
+            self.sphere.set_material(material)
+        }
+        fn parents(&self) -> &ParentChain {
+            &self.parents
+        }
+        fn set_parents(&mut self, parents: ParentChain) {
+            self.parents = parents
+        }
+        fn local_intersect<'a>(&'a self, ray: &Ray) -> Intersections<'a> {
+            self.rays.set(self.rays.get() + 1);
+            let ts: Vec<f32> = self.sphere.local_intersect(ray).iter().map(|i| i.t).collect();
+            ts.into_iter().map(|t| Intersection::new(t, self)).collect()
+        }
+        fn local_normal_at(&self, point: &Vert4) -> Option<Vert4> {
+            self.sphere.local_normal_at(point)
+        }
+        fn bounds(&self) -> Aabb {
+            self.sphere.bounds()
+        }
+    }
+
+    #[test]
+    fn creating_a_new_group() {
+        let g = Group::new();
+        assert_eq!(g.transform(), &Transform::identity());
+        assert!(g.is_empty() && g.parents().is_empty());
+    }
+    #[test]
+    fn adding_a_child_to_a_group() {
+        let g = Group::new().with_child(Sphere::new());
+        assert_eq!(g.len(), 1);
+        assert_eq!(g.children()[0].parents().depth(), 1);
+        let outer = Group::new().with_child(g);
+        assert!(outer.parents().is_empty());
+    }
+    #[test]
+    fn intersecting_a_ray_with_an_empty_group() {
+        let g = Group::new();
+        let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+        assert!(g.local_intersect(&r).is_empty());
+    }
+    #[test]
+    fn intersecting_a_ray_with_a_nonempty_group() {
+        let g = Group::new()
+            .with_child(Sphere::new())
+            .with_child(Sphere::new().with_transform(Transform::identity().translate(0., 0., -3.)))
+            .with_child(Sphere::new().with_transform(Transform::identity().translate(5., 0., 0.)));
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        let xs = g.local_intersect(&r);
+        let (s1, s2) = (g.children()[0].as_ref(), g.children()[1].as_ref());
+        assert_eq!(xs.len(), 4);
+        assert!(is(xs[0].object, s2) && is(xs[1].object, s2));
+        assert!(is(xs[2].object, s1) && is(xs[3].object, s1));
+    }
+    #[test]
+    fn intersecting_a_transformed_group() {
+        let g = Group::new()
+            .with_child(Sphere::new().with_transform(Transform::identity().translate(5., 0., 0.)))
+            .with_transform(Transform::identity().scale(2., 2., 2.));
+        let r = Ray::new(Vert4::point(10., 0., -10.), Vert4::vector(0., 0., 1.));
+        assert_eq!((&g as &dyn Shape).intersect(&r).unwrap().len(), 2);
+    }
+    // The sphere at (5, 0, 0) of g2 in g1, hit by a ray along z
+    fn nested(g1: Transform, g2: Transform) -> Group {
+        let g2 = Group::new().with_transform(g2).with_child(Sphere::new().with_transform(Transform::identity().translate(5., 0., 0.)));
+        Group::new().with_transform(g1).with_child(g2)
+    }
+    fn nested_sphere(g1: &Group) -> &dyn Shape {
+        let r = Ray::new(Vert4::point(0., 0., -20.), Vert4::vector(0., 0., 1.));
+        (g1 as &dyn Shape).intersect(&r).unwrap().hit().expect("the ray hits the sphere").object
+    }
+    #[test]
+    fn converting_a_point_from_world_to_object_space() {
+        let g1 = nested(Transform::identity().rotate_y(FRAC_PI_2), Transform::identity().scale(2., 2., 2.));
+        let s = nested_sphere(&g1);
+        assert_eq!(s.parents().depth(), 2);
+        assert_eq!(s.world_to_object(&Vert4::point(-2., 0., -10.)), Vert4::point(0., 0., -1.));
+    }
+    #[test]
+    fn converting_a_normal_from_object_to_world_space() {
+        let g1 = nested(Transform::identity().rotate_y(FRAC_PI_2), Transform::identity().scale(1., 2., 3.));
+        let s = nested_sphere(&g1);
+        let k = 3f32.sqrt() / 3.;
+        assert_eq!(s.normal_to_world(&Vert4::vector(k, k, k)), Vert4::vector(0.2857, 0.4286, -0.8571));
+    }
+    #[test]
+    fn finding_the_normal_on_a_child_object() {
+        let g1 = nested(Transform::identity().rotate_y(FRAC_PI_2), Transform::identity().scale(1., 2., 3.));
+        let s = nested_sphere(&g1);
+        let n = s.normal_at(&Vert4::point(1.7321, 1.1547, -5.5774));
+        assert_eq!(n, Some(Vert4::vector(0.2857, 0.4286, -0.8571)));
+    }
+    #[test]
+    fn a_group_has_no_normals() {
+        let g = Group::new().with_child(Sphere::new());
+        assert!((&g as &dyn Shape).normal_at(&Vert4::point(1., 0., 0.)).is_none());
+    }
+    #[test]
+    fn transforming_a_group_after_adding_children() {
+        let mut g1 = nested(Transform::identity(), Transform::identity().scale(2., 2., 2.));
+        g1.set_transform(Transform::identity().rotate_y(FRAC_PI_2));
+        let s = nested_sphere(&g1);
+        assert_eq!(s.world_to_object(&Vert4::point(-2., 0., -10.)), Vert4::point(0., 0., -1.));
+        let bounds = s.world_bounds();
+        assert_eq!(bounds, Aabb::new(&Vert4::point(-2., -2., -12.), &Vert4::point(2., 2., -8.)));
+    }
+    #[test]
+    fn a_group_is_bounded_by_its_children() {
+        let g = Group::new()
+            .with_child(Sphere::new().with_transform(Transform::identity().scale(2., 2., 2.).translate(2., 5., -3.)))
+            .with_child(Cylinder::capped(-2., 2.).with_transform(Transform::identity().scale(0.5, 1., 0.5).translate(-4., -1., 4.)));
+        assert_eq!(g.bounds(), Aabb::new(&Vert4::point(-4.5, -3., -5.), &Vert4::point(4., 7., 4.5)));
+        assert_eq!(Group::new().bounds(), Aabb::empty());
+        assert!(!Group::new().with_child(Plane::new()).bounds().is_finite());
+    }
+    #[test]
+    fn rays_missing_a_childs_box_skip_it() {
+        let (near, far) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+        let g = Group::new()
+            .with_child(Counting::new(&near))
+            .with_child(Counting::new(&far).with_transform(Transform::identity().translate(5., 0., 0.)));
+        g.local_intersect(&Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.)));
+        assert_eq!((near.get(), far.get()), (1, 0));
+        g.local_intersect(&Ray::new(Vert4::point(5., 0.5, -5.), Vert4::vector(0., 0., 1.)));
+        assert_eq!((near.get(), far.get()), (1, 1));
+    }
+    #[test]
+    fn rays_missing_a_groups_box_skip_all_children() {
+        let (a, b) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+        let inner = Group::new()
+            .with_child(Counting::new(&a))
+            .with_child(Counting::new(&b).with_transform(Transform::identity().translate(3., 0., 0.)))
+            .with_transform(Transform::identity().translate(0., 10., 0.));
+        let g = Group::new().with_child(inner);
+        let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+        assert!((&g as &dyn Shape).intersect(&r).unwrap().is_empty());
+        assert_eq!((a.get(), b.get()), (0, 0));
+        let r = Ray::new(Vert4::point(3., 10., -5.), Vert4::vector(0., 0., 1.));
+        assert_eq!(ts(&(&g as &dyn Shape).intersect(&r).unwrap()), vec![4., 6.]);
+        assert_eq!((a.get(), b.get()), (0, 1));
+    }
+    #[test]
+    fn a_large_group_matches_a_scan_of_its_children() {
+        let mut g = Group::new().with_transform(Transform::identity().rotate_y(0.3));
+        for i in 0..1000 {
+            let (x, y, z) = ((i % 10) as f32, (i / 10 % 10) as f32, (i / 100) as f32);
+            let scale = 0.2 + 0.02 * (i % 7) as f32;
+            g.add_child(Sphere::new().with_transform(Transform::identity().scale(scale, scale, scale).translate(x, y, z)));
+        }
+        g.add_child(Plane::new().with_transform(Transform::identity().translate(0., -0.5, 0.)));
+        let shape: &dyn Shape = &g;
+        for i in 0..200 {
+            let k = i as f32;
+            let origin = Vert4::point(-5. + (k * 0.37) % 20., 12. - (k * 0.61) % 15., -5. + (k * 0.23) % 3.);
+            let target = Vert4::point((k * 0.13) % 9., (k * 0.29) % 9., (k * 0.41) % 9.);
+            let r = Ray::new(origin, target - origin);
+            let local = r.transform(g.transform().inverse()).unwrap();
+            let mut expected: Vec<f32> =
+                g.children().iter().flat_map(|child| ts(&child.intersect(&local).unwrap())).collect();
+            expected.sort_by(f32::total_cmp);
+            assert_eq!(ts(&shape.intersect(&r).unwrap()), expected, "ray {i}");
+            for t_max in [0.5, 1., f32::INFINITY] {
+                let hit = expected.iter().any(|&t| 0. <= t && t < t_max);
+                assert_eq!(shape.any_hit(&r, t_max), Ok(hit), "ray {i} before {t_max}");
+            }
+        }
+    }
+}
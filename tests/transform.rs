@@ -0,0 +1,72 @@
+use geometry::matrix::{Inverse, ShearingProportion};
+use geometry::{Matr4, Matrix, Transform, Vert4};
+
+#[test]
+fn building_from_a_matrix_caches_the_inverses() {
+    let matrix = Matr4::translation(1., 2., 3.) * Matr4::scaling(2., -1., 4.);
+    let transform = Transform::try_from(matrix).unwrap();
+    let inverse = Matr4::scaling(0.5, -1., 0.25) * Matr4::translation(-1., -2., -3.);
+    assert_eq!(transform.inverse(), &inverse);
+    assert_eq!(transform.inverse_transpose(), &inverse.transpose());
+    assert!(Transform::try_from(Matr4::scaling(1., 0., 1.)).is_err());
+}
+#[test]
+fn composing_updates_the_inverses() {
+    let a = Transform::try_from(Matr4::rotation_z_rad(0.3) * Matr4::translation(-4., 0., 1.)).unwrap();
+    let b = Transform::identity().scale(3., 1., 0.5).rotate_y(1.2);
+    let product = &a * &b;
+    let expected = a.matrix() * b.matrix();
+    assert_eq!(product.matrix(), &expected);
+    assert_eq!(product.inverse(), &expected.inverse().unwrap());
+    assert_eq!(product.inverse_transpose(), &expected.inverse().unwrap().transpose());
+}
+#[test]
+fn the_builder_keeps_the_inverses_in_step() {
+    let transform = Transform::identity()
+        .shear(
+            ShearingProportion::new(1., 0.),
+            ShearingProportion::default(),
+            ShearingProportion::new(0., 2.),
+        )
+        .rotate_x(0.4)
+        .rotate_z(-1.)
+        .rotate_axis(Vert4::vector(1., 1., 1.), 2.)
+        .scale(2., 3., 4.)
+        .translate(5., -3., 2.);
+    assert_eq!(transform.inverse(), &transform.matrix().inverse().unwrap());
+    assert_eq!(transform.inverse_transpose(), &transform.inverse().transpose());
+}
+#[test]
+#[should_panic(expected = "cannot scale by zero")]
+fn scaling_by_zero_panics() {
+    let _ = Transform::<f32>::identity().scale(1., 0., 1.);
+}
+#[test]
+fn fallible_builders_hand_back_the_transform() {
+    let transform = Transform::identity().translate(1., 2., 3.);
+    let expected = Transform::identity().translate(1., 2., 3.);
+    let transform = transform.try_scale(2., 0., 2.).unwrap_err();
+    assert_eq!(transform, expected);
+    let transform = transform
+        .try_shear(ShearingProportion::new(1., 0.), ShearingProportion::new(1., 0.), ShearingProportion::default())
+        .unwrap_err();
+    assert_eq!(transform, expected);
+    let scaled = transform.try_scale(2., 1., 1.).unwrap();
+    assert_eq!(scaled, expected.scale(2., 1., 1.));
+}
+#[test]
+fn applying_to_points_vectors_and_normals() {
+    let transform = Transform::identity().scale(1., 0.5, 1.).translate(0., 1., 0.);
+    assert_eq!(transform.apply_point(&Vert4::point(2., 2., 2.)), Vert4::point(2., 2., 2.));
+    assert_eq!(transform.apply_vector(&Vert4::vector(2., 2., 2.)), Vert4::vector(2., 1., 2.));
+    // Squashing y makes the surface at 45° steeper, so the normal tilts upwards
+    let normal = transform.apply_normal(&Vert4::vector(1., 1., 0.));
+    assert_eq!(normal, Vert4::vector(1., 2., 0.));
+}
+#[test]
+fn normals_keep_w_at_zero() {
+    let transform = Transform::identity().translate(1., 2., 3.).rotate_y(0.7);
+    let normal = transform.apply_normal(&Vert4::vector(0., 0., 1.));
+    assert_eq!(normal.w(), 0.);
+    assert_eq!(normal, transform.apply_vector(&Vert4::vector(0., 0., 1.)));
+}
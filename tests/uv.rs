@@ -0,0 +1,196 @@
+use core::f32::consts::FRAC_1_SQRT_2;
+
+use geometry::uv::{CubeFace, Filter, ImageTexture, UvCheckers, cube_face, cube_face_uv, cube_map};
+use geometry::uv::{cylindrical_map, planar_map, spherical_map};
+use geometry::{Canvas, Color, Pattern, TextureMap, Transform, UvMapping, UvPattern, Vert4};
+
+const RED: Color = Color::new(1., 0., 0.);
+const GREEN: Color = Color::new(0., 1., 0.);
+const BLUE: Color = Color::new(0., 0., 1.);
+
+fn assert_uv((u, v): (f32, f32), expected: (f32, f32), point: &Vert4) {
+    let close = |l: f32, r: f32| (l - r).abs() < 1e-4;
+    assert!(close(u, expected.0) && close(v, expected.1), "{point:?} maps to ({u}, {v}), not {expected:?}");
+}
+// Red and green on top, blue and white below
+fn two_by_two() -> Canvas {
+    Canvas::from_ppm(b"P3\n2 2\n255\n255 0 0  0 255 0\n0 0 255  255 255 255\n").unwrap()
+}
+//
+// Mapping tests
+//
+#[test]
+fn using_a_spherical_mapping_on_a_3d_point() {
+    let k = FRAC_1_SQRT_2;
+    for (point, uv) in [
+        (Vert4::point(0., 0., -1.), (0., 0.5)),
+        (Vert4::point(1., 0., 0.), (0.25, 0.5)),
+        (Vert4::point(0., 0., 1.), (0.5, 0.5)),
+        (Vert4::point(-1., 0., 0.), (0.75, 0.5)),
+        (Vert4::point(0., 1., 0.), (0.5, 1.)),
+        (Vert4::point(0., -1., 0.), (0.5, 0.)),
+        (Vert4::point(k, k, 0.), (0.25, 0.75)),
+        (Vert4::point(0., 0., -3.), (0., 0.5)),
+    ] {
+        assert_uv(spherical_map(&point), uv, &point);
+        assert_uv(UvMapping::Spherical.map(&point), uv, &point);
+    }
+}
+#[test]
+fn the_seam_behind_the_y_axis_maps_to_the_left_edge() {
+    let point = Vert4::point(-0., 0., -1.);
+    assert_eq!(spherical_map(&point).0, 0.);
+    assert_eq!(cylindrical_map(&point).0, 0.);
+}
+#[test]
+fn using_a_planar_mapping_on_a_3d_point() {
+    for (point, uv) in [
+        (Vert4::point(0.25, 0., 0.5), (0.25, 0.5)),
+        (Vert4::point(0.25, 0., -0.25), (0.25, 0.75)),
+        (Vert4::point(0.25, 0.5, -0.25), (0.25, 0.75)),
+        (Vert4::point(1.25, 0., 0.5), (0.25, 0.5)),
+        (Vert4::point(0.25, 0., -1.75), (0.25, 0.25)),
+        (Vert4::point(1., 0., -1.), (0., 0.)),
+        (Vert4::point(0., 0., 0.), (0., 0.)),
+    ] {
+        assert_uv(planar_map(&point), uv, &point);
+        assert_uv(UvMapping::Planar.map(&point), uv, &point);
+    }
+}
+#[test]
+fn using_a_cylindrical_mapping_on_a_3d_point() {
+    let k = FRAC_1_SQRT_2;
+    for (point, uv) in [
+        (Vert4::point(0., 0., -1.), (0., 0.)),
+        (Vert4::point(0., 0.5, -1.), (0., 0.5)),
+        (Vert4::point(0., 1., -1.), (0., 0.)),
+        (Vert4::point(k, 0.5, -k), (0.125, 0.5)),
+        (Vert4::point(1., 0.5, 0.), (0.25, 0.5)),
+        (Vert4::point(k, 0.5, k), (0.375, 0.5)),
+        (Vert4::point(0., -0.25, 1.), (0.5, 0.75)),
+        (Vert4::point(-k, 0.5, k), (0.625, 0.5)),
+        (Vert4::point(-1., 1.25, 0.), (0.75, 0.25)),
+        (Vert4::point(-k, 0.5, -k), (0.875, 0.5)),
+    ] {
+        assert_uv(cylindrical_map(&point), uv, &point);
+        assert_uv(UvMapping::Cylindrical.map(&point), uv, &point);
+    }
+}
+#[test]
+fn identifying_the_face_of_a_cube_from_a_point() {
+    for (point, face) in [
+        (Vert4::point(-1., 0.5, -0.25), CubeFace::Left),
+        (Vert4::point(1.1, -0.75, 0.8), CubeFace::Right),
+        (Vert4::point(0.1, 0.6, 0.9), CubeFace::Front),
+        (Vert4::point(-0.7, 0., -2.), CubeFace::Back),
+        (Vert4::point(0.5, 1., 0.9), CubeFace::Up),
+        (Vert4::point(-0.2, -1.3, 1.1), CubeFace::Down),
+    ] {
+        assert_eq!(cube_face(&point), face, "{point:?}");
+    }
+}
+#[test]
+fn uv_mapping_the_faces_of_a_cube() {
+    for (face, upper_left, lower_right) in [
+        (CubeFace::Front, Vert4::point(-0.5, 0.5, 1.), Vert4::point(0.5, -0.5, 1.)),
+        (CubeFace::Back, Vert4::point(0.5, 0.5, -1.), Vert4::point(-0.5, -0.5, -1.)),
+        (CubeFace::Left, Vert4::point(-1., 0.5, -0.5), Vert4::point(-1., -0.5, 0.5)),
+        (CubeFace::Right, Vert4::point(1., 0.5, 0.5), Vert4::point(1., -0.5, -0.5)),
+        (CubeFace::Up, Vert4::point(-0.5, 1., -0.5), Vert4::point(0.5, 1., 0.5)),
+        (CubeFace::Down, Vert4::point(-0.5, -1., 0.5), Vert4::point(0.5, -1., -0.5)),
+    ] {
+        assert_uv(cube_face_uv(face, &upper_left), (0.25, 0.75), &upper_left);
+        assert_uv(cube_face_uv(face, &lower_right), (0.75, 0.25), &lower_right);
+    }
+}
+#[test]
+fn a_cube_mapping_lays_the_faces_out_as_a_cross() {
+    for (point, uv) in [
+        (Vert4::point(-1., 0., 0.), (0.125, 0.5)),
+        (Vert4::point(0., 0., 1.), (0.375, 0.5)),
+        (Vert4::point(1., 0., 0.), (0.625, 0.5)),
+        (Vert4::point(0., 0., -1.), (0.875, 0.5)),
+        (Vert4::point(0., 1., 0.), (0.375, 2.5 / 3.)),
+        (Vert4::point(0., -1., 0.), (0.375, 0.5 / 3.)),
+        // Faces meet at their shared edges
+        (Vert4::point(1., 0., 1.), (0.5, 0.5)),
+        (Vert4::point(0., 1., 1.), (0.375, 2. / 3.)),
+    ] {
+        assert_uv(cube_map(&point), uv, &point);
+        assert_uv(UvMapping::Cube.map(&point), uv, &point);
+    }
+}
+//
+// UV pattern tests
+//
+#[test]
+fn checker_pattern_in_2d() {
+    let checkers = UvCheckers::new(2., 2., Color::BLACK, Color::WHITE);
+    assert_eq!(checkers.uv_color_at(0., 0.), Color::BLACK);
+    assert_eq!(checkers.uv_color_at(0.5, 0.), Color::WHITE);
+    assert_eq!(checkers.uv_color_at(0., 0.5), Color::WHITE);
+    assert_eq!(checkers.uv_color_at(0.5, 0.5), Color::BLACK);
+    assert_eq!(checkers.uv_color_at(1., 1.), Color::BLACK);
+}
+#[test]
+fn nearest_filtering_picks_the_closest_pixel() {
+    let texture = ImageTexture::new(two_by_two(), Filter::Nearest);
+    assert_eq!(texture.filter(), Filter::Nearest);
+    assert_eq!(texture.uv_color_at(0., 1.), RED);
+    assert_eq!(texture.uv_color_at(1., 1.), GREEN);
+    assert_eq!(texture.uv_color_at(0., 0.), BLUE);
+    assert_eq!(texture.uv_color_at(1., 0.), Color::WHITE);
+    assert_eq!(texture.uv_color_at(0.4, 0.6), RED);
+    assert_eq!(texture.uv_color_at(0.6, 0.4), Color::WHITE);
+    // Clamped to the edge
+    assert_eq!(texture.uv_color_at(-1., 2.), RED);
+    assert_eq!(texture.uv_color_at(3., 0.2), Color::WHITE);
+}
+#[test]
+fn bilinear_filtering_interpolates_between_pixels() {
+    let texture = ImageTexture::new(two_by_two(), Filter::Bilinear);
+    assert_eq!(texture.uv_color_at(0., 1.), RED);
+    assert_eq!(texture.uv_color_at(1., 0.), Color::WHITE);
+    assert_eq!(texture.uv_color_at(0.25, 1.), Color::new(0.75, 0.25, 0.));
+    assert_eq!(texture.uv_color_at(0., 0.5), Color::new(0.5, 0., 0.5));
+    assert_eq!(texture.uv_color_at(0.5, 0.5), Color::new(0.5, 0.5, 0.5));
+    assert_eq!(texture.uv_color_at(2., -1.), Color::WHITE);
+}
+#[test]
+#[should_panic]
+fn an_empty_canvas_is_no_texture() {
+    ImageTexture::new(Canvas::new(0, 4), Filter::Nearest);
+}
+//
+// Texture map tests
+//
+#[test]
+fn using_a_texture_map_pattern_with_a_spherical_map() {
+    let pattern = TextureMap::new(UvCheckers::new(16., 8., Color::BLACK, Color::WHITE), UvMapping::Spherical);
+    assert_eq!(pattern.mapping(), UvMapping::Spherical);
+    let pattern: &dyn Pattern = &pattern;
+    for (point, expected) in [
+        (Vert4::point(0.4315, 0.4670, 0.7719), Color::WHITE),
+        (Vert4::point(-0.9654, 0.2552, -0.0534), Color::BLACK),
+        (Vert4::point(0.1039, 0.7090, 0.6975), Color::WHITE),
+        (Vert4::point(-0.4986, -0.7856, -0.3663), Color::BLACK),
+        (Vert4::point(-0.0317, -0.9395, 0.3411), Color::BLACK),
+        (Vert4::point(0.4809, -0.7721, 0.4154), Color::BLACK),
+        (Vert4::point(0.0285, -0.9612, -0.2745), Color::BLACK),
+        (Vert4::point(-0.5734, -0.2162, -0.7903), Color::WHITE),
+        (Vert4::point(0.7688, -0.1470, 0.6223), Color::BLACK),
+        (Vert4::point(-0.7652, 0.2175, 0.6060), Color::BLACK),
+    ] {
+        assert_eq!(pattern.color_at(&point), expected, "{point:?}");
+    }
+}
+#[test]
+fn a_texture_map_samples_an_image_in_pattern_space() {
+    let texture = ImageTexture::new(two_by_two(), Filter::Nearest);
+    let pattern = TextureMap::new(texture, UvMapping::Planar).with_transform(Transform::identity().scale(2., 2., 2.));
+    let pattern: &dyn Pattern = &pattern;
+    assert_eq!(pattern.color_at(&Vert4::point(0.2, 0., 1.8)), RED);
+    assert_eq!(pattern.color_at(&Vert4::point(1.8, 0., 1.8)), GREEN);
+    assert_eq!(pattern.color_at(&Vert4::point(0.2, 0., 0.2)), BLUE);
+    assert_eq!(pattern.color_at(&Vert4::point(3.8, 5., 2.2)), Color::WHITE);
+}
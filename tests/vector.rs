@@ -91,3 +91,18 @@ fn the_cross_product_of_two_vectors() {
     let v2 = Cross::cross(&b, &a);
     assert_eq!(v2, geometry::vector!(1., -2., 1.));
 }
+mod reflecting {
+    #[test]
+    fn a_vector_approaching_at_45_degrees() {
+        let v = geometry::vector!(1., -1., 0.);
+        let n = geometry::vector!(0., 1., 0.);
+        assert_eq!(v.reflect(&n), geometry::vector!(1., 1., 0.));
+    }
+    #[test]
+    fn a_vector_off_a_slanted_surface() {
+        let v = geometry::vector!(0., -1., 0.);
+        let k = 2f32.sqrt() / 2.;
+        let n = geometry::vector!(k, k, 0.);
+        assert_eq!(v.reflect(&n), geometry::vector!(1., 0., 0.));
+    }
+}
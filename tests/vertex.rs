@@ -58,3 +58,127 @@ mod point {
         }
     }
 }
+mod vert2 {
+    use geometry::Vert2;
+    use geometry::vertex::{Cross, Dot, Mag, Norm};
+    #[test]
+    fn adding_and_subtracting() {
+        let a = Vert2::new(3.0, -2.0);
+        let b = Vert2::new(-2.0, 3.0);
+        assert_eq!(&a + &b, Vert2::new(1.0, 1.0));
+        assert_eq!(a - b, Vert2::new(5.0, -5.0));
+    }
+    #[test]
+    fn scalar_multiplication_and_division() {
+        let a = Vert2::new(1.0, -2.0);
+        assert_eq!(&a * 3.5, Vert2::new(3.5, -7.0));
+        assert_eq!(a / 2.0, Vert2::new(0.5, -1.0));
+    }
+    #[test]
+    fn negating() {
+        assert_eq!(-Vert2::new(1.0, -2.0), Vert2::new(-1.0, 2.0));
+    }
+    #[test]
+    fn dot_and_perp_dot() {
+        let a = Vert2::new(1.0, 2.0);
+        let b = Vert2::new(3.0, 4.0);
+        assert_eq!(Dot::dot(&a, &b), 11.0);
+        assert_eq!(Cross::cross(&a, &b), -2.0);
+        assert_eq!(Vert2::X.cross(Vert2::Y), 1.0);
+    }
+    #[test]
+    fn magnitude_and_normalizing() {
+        let a = Vert2::new(3.0, 4.0);
+        assert_eq!(a.mag(), 5.0);
+        assert_eq!(a.norm(), Vert2::new(0.6, 0.8));
+    }
+    #[test]
+    fn equality_compares_every_lane() {
+        assert_ne!(Vert2::new(1.0, 2.0), Vert2::new(1.0, 7.0));
+        assert_eq!(Vert2::new(1.0, 2.0), Vert2::new(1.00001, 2.0));
+    }
+}
+mod vert3 {
+    use geometry::Vert3;
+    use geometry::vertex::{Cross, Dot, Mag, Norm};
+    #[test]
+    fn adding_and_subtracting() {
+        let a = Vert3::new(3.0, -2.0, 5.0);
+        let b = Vert3::new(-2.0, 3.0, 1.0);
+        assert_eq!(&a + &b, Vert3::new(1.0, 1.0, 6.0));
+        assert_eq!(a - b, Vert3::new(5.0, -5.0, 4.0));
+    }
+    #[test]
+    fn scalar_multiplication_and_division() {
+        let mut a = Vert3::new(1.0, -2.0, 3.0);
+        assert_eq!(&a * 0.5, Vert3::new(0.5, -1.0, 1.5));
+        a /= 2.0;
+        assert_eq!(a, Vert3::new(0.5, -1.0, 1.5));
+    }
+    #[test]
+    fn negating() {
+        assert_eq!(-Vert3::new(1.0, -2.0, 3.0), Vert3::new(-1.0, 2.0, -3.0));
+    }
+    #[test]
+    fn dot_product() {
+        let a = Vert3::new(1.0, 2.0, 3.0);
+        let b = Vert3::new(2.0, 3.0, 4.0);
+        assert_eq!(a.dot(b), 20.0);
+    }
+    #[test]
+    fn cross_product() {
+        let a = Vert3::new(1.0, 2.0, 3.0);
+        let b = Vert3::new(2.0, 3.0, 4.0);
+        assert_eq!(Cross::cross(&a, &b), Vert3::new(-1.0, 2.0, -1.0));
+        assert_eq!(Cross::cross(&b, &a), Vert3::new(1.0, -2.0, 1.0));
+    }
+    #[test]
+    fn magnitude_and_normalizing() {
+        let a = Vert3::new(1.0, 2.0, 3.0);
+        assert_eq!(a.mag(), 14.0_f32.sqrt());
+        assert_eq!(a.norm(), Vert3::new(0.26726, 0.53452, 0.80178));
+    }
+    #[test]
+    fn equality_compares_every_lane() {
+        assert_ne!(Vert3::new(1.0, 2.0, 3.0), Vert3::new(1.0, 7.0, 3.0));
+        assert_ne!(Vert3::new(1.0, 2.0, 3.0), Vert3::new(1.0, 2.0, -3.0));
+        assert_eq!(Vert3::new(1.0, 2.0, 3.0), Vert3::new(1.0, 2.0, 3.00001));
+    }
+}
+mod approximate_equality {
+    #[test]
+    fn every_lane_is_compared() {
+        let a = geometry::vertex!(1.0, 2.0, 3.0, 4.0);
+        assert_ne!(a, geometry::vertex!(1.0, 5.0, 3.0, 4.0));
+        assert_ne!(a, geometry::vertex!(1.0, 2.0, 3.0, 0.0));
+        assert_eq!(a, geometry::vertex!(1.00001, 2.0, 3.0, 4.0));
+    }
+}
+mod double_precision {
+    use geometry::DVert4;
+    use geometry::vertex::{Cross, Dot, Mag, Norm};
+    #[test]
+    fn constructing_with_typed_macros() {
+        let p = geometry::point!(f64; 4, -4, 3);
+        assert_eq!(p, DVert4::new(4.0, -4.0, 3.0, 1.0));
+        assert!(p.is_point());
+        let v = geometry::vector!(f64; 4, -4, 3);
+        assert!(v.is_vector());
+    }
+    #[test]
+    fn large_coordinates_keep_precision() {
+        let a = DVert4::point(1.0e9 + 0.5, 0.0, 0.0);
+        let b = DVert4::point(1.0e9, 0.0, 0.0);
+        assert_eq!(a - b, DVert4::vector(0.5, 0.0, 0.0));
+    }
+    #[test]
+    fn products_and_normalizing() {
+        let a = DVert4::vector(1.0, 2.0, 3.0);
+        let b = DVert4::vector(2.0, 3.0, 4.0);
+        assert_eq!(Dot::dot(&a, &b), 20.0);
+        assert_eq!(Cross::cross(&a, &b), DVert4::vector(-1.0, 2.0, -1.0));
+        assert_eq!(a.mag(), 14.0_f64.sqrt());
+        assert_eq!(a.norm(), DVert4::vector(0.2672612419, 0.5345224838, 0.8017837257));
+        assert_ne!(a.norm(), DVert4::vector(0.26726, 0.53452, 0.80178));
+    }
+}
@@ -0,0 +1,412 @@
+use core::f32::consts::FRAC_PI_2;
+
+use geometry::shapes::{Group, Intersection, Intersections, Plane, Shape, Sphere};
+use geometry::world::Computations;
+use geometry::{Camera, Color, Material, PointLight, Ray, Transform, Vert4, World};
+
+const EPSILON: f32 = 1e-4;
+
+fn default_world() -> World {
+    let mut w = World::new();
+    w.add_light(PointLight::new(Vert4::point(-10., 10., -10.), Color::WHITE));
+    w.add_object(Sphere::new().with_material(Material {
+        color: Color::new(0.8, 1., 0.6),
+        diffuse: 0.7,
+        specular: 0.2,
+        ..Material::default()
+    }));
+    w.add_object(Sphere::new().with_transform(Transform::identity().scale(0.5, 0.5, 0.5)));
+    w
+}
+fn glass_sphere() -> Sphere {
+    Sphere::new().with_material(Material { transparency: 1., refractive_index: 1.5, ..Material::default() })
+}
+// Shading sums several f32 terms, a little looser than `Color`'s equality
+fn assert_color(actual: Color, r: f32, g: f32, b: f32) {
+    let close = |l: f32, r: f32| (l - r).abs() < 1e-3;
+    assert!(close(actual.r(), r) && close(actual.g(), g) && close(actual.b(), b), "{actual:?} != ({r}, {g}, {b})");
+}
+//
+// Intersection tests
+//
+#[test]
+fn creating_a_world() {
+    let w = World::new();
+    assert!(w.objects.is_empty() && w.lights.is_empty());
+}
+#[test]
+fn intersect_a_world_with_a_ray() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let ts: Vec<f32> = w.intersect(&r).unwrap().iter().map(|i| i.t).collect();
+    assert_eq!(ts, vec![4., 4.5, 5.5, 6.]);
+}
+#[test]
+fn intersecting_a_world_with_a_malformed_ray_fails() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::point(0., 0., 1.));
+    assert!(w.intersect(&r).is_err());
+    assert_eq!(w.color_at(&r), Color::BLACK);
+}
+//
+// Computations tests
+//
+#[test]
+fn precomputing_the_state_of_an_intersection() {
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let shape = Sphere::new();
+    let i = Intersection::new(4., &shape);
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_eq!(comps.t, 4.);
+    assert_eq!(comps.point, Vert4::point(0., 0., -1.));
+    assert_eq!(comps.eyev, Vert4::vector(0., 0., -1.));
+    assert_eq!(comps.normalv, Vert4::vector(0., 0., -1.));
+    assert!(!comps.inside);
+}
+#[test]
+fn the_hit_when_an_intersection_occurs_on_the_inside() {
+    let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+    let shape = Sphere::new();
+    let i = Intersection::new(1., &shape);
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_eq!(comps.point, Vert4::point(0., 0., 1.));
+    assert_eq!(comps.eyev, Vert4::vector(0., 0., -1.));
+    assert!(comps.inside);
+    // Inverted, it would have been (0, 0, 1)
+    assert_eq!(comps.normalv, Vert4::vector(0., 0., -1.));
+}
+#[test]
+fn the_hit_should_offset_the_point() {
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let shape = Sphere::new().with_transform(Transform::identity().translate(0., 0., 1.));
+    let i = Intersection::new(5., &shape);
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert!(comps.over_point.z() < -EPSILON / 2.);
+    assert!(comps.point.z() > comps.over_point.z());
+}
+#[test]
+fn the_under_point_is_offset_below_the_surface() {
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let shape = glass_sphere().with_transform(Transform::identity().translate(0., 0., 1.));
+    let i = Intersection::new(5., &shape);
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert!(comps.under_point.z() > EPSILON / 2.);
+    assert!(comps.point.z() < comps.under_point.z());
+}
+#[test]
+fn precomputing_the_reflection_vector() {
+    let shape = Plane::new();
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 1., -1.), Vert4::vector(0., -k, k));
+    let i = Intersection::new(2f32.sqrt(), &shape);
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_eq!(comps.reflectv, Vert4::vector(0., k, k));
+}
+#[test]
+fn finding_n1_and_n2_at_various_intersections() {
+    let a = glass_sphere().with_transform(Transform::identity().scale(2., 2., 2.));
+    let mut b = glass_sphere().with_transform(Transform::identity().translate(0., 0., -0.25));
+    b.set_material(Material { transparency: 1., refractive_index: 2., ..Material::default() });
+    let mut c = glass_sphere().with_transform(Transform::identity().translate(0., 0., 0.25));
+    c.set_material(Material { transparency: 1., refractive_index: 2.5, ..Material::default() });
+    let r = Ray::new(Vert4::point(0., 0., -4.), Vert4::vector(0., 0., 1.));
+    let xs = Intersections::new(vec![
+        Intersection::new(2., &a),
+        Intersection::new(2.75, &b),
+        Intersection::new(3.25, &c),
+        Intersection::new(4.75, &b),
+        Intersection::new(5.25, &c),
+        Intersection::new(6., &a),
+    ]);
+    let expected = [(1., 1.5), (1.5, 2.), (2., 2.5), (2.5, 2.5), (2.5, 1.5), (1.5, 1.)];
+    for (i, (n1, n2)) in xs.iter().zip(expected) {
+        let comps = Computations::prepare(i, &r, &xs).unwrap();
+        assert_eq!((comps.n1, comps.n2), (n1, n2), "at t = {}", i.t);
+    }
+}
+//
+// Shading tests
+//
+#[test]
+fn shading_an_intersection() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let i = Intersection::new(4., w.objects[0].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.38066, 0.47583, 0.2855);
+}
+#[test]
+fn shading_an_intersection_from_the_inside() {
+    let mut w = default_world();
+    w.lights = vec![PointLight::new(Vert4::point(0., 0.25, 0.), Color::WHITE)];
+    let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+    let i = Intersection::new(0.5, w.objects[1].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.90498, 0.90498, 0.90498);
+}
+#[test]
+fn the_color_when_a_ray_misses() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 1., 0.));
+    assert_eq!(w.color_at(&r), Color::BLACK);
+}
+#[test]
+fn the_color_when_a_ray_hits() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    assert_color(w.color_at(&r), 0.38066, 0.47583, 0.2855);
+}
+#[test]
+fn the_color_with_an_intersection_behind_the_ray() {
+    let mut w = default_world();
+    for object in &mut w.objects {
+        let material = Material { ambient: 1., color: object.material().color.clamp(), ..Material::default() };
+        object.set_material(material);
+    }
+    let r = Ray::new(Vert4::point(0., 0., 0.75), Vert4::vector(0., 0., -1.));
+    assert_color(w.color_at(&r), 1., 1., 1.);
+}
+#[test]
+fn shading_with_several_lights_adds_them_up() {
+    let mut w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    w.add_light(PointLight::new(Vert4::point(-10., 10., -10.), Color::WHITE));
+    assert_color(w.color_at(&r), 0.76132, 0.95166, 0.571);
+}
+#[test]
+fn shading_through_a_group_matches_the_ungrouped_shapes() {
+    let mut w = World::new();
+    w.add_light(PointLight::new(Vert4::point(-10., 10., -10.), Color::WHITE));
+    let moved = |x: f32| Transform::identity().scale(0.5, 0.5, 0.5).translate(x, 0., 0.);
+    w.add_object(Sphere::new().with_transform(moved(-0.5)));
+    w.add_object(Sphere::new().with_transform(moved(0.5)));
+    let mut grouped = World::new();
+    grouped.add_light(PointLight::new(Vert4::point(-10., 10., -10.), Color::WHITE));
+    let inner = Group::new().with_child(Sphere::new().with_transform(Transform::identity().translate(1., 0., 0.)));
+    grouped.add_object(
+        Group::new()
+            .with_child(Sphere::new().with_transform(Transform::identity().translate(-1., 0., 0.)))
+            .with_child(inner)
+            .with_transform(Transform::identity().scale(0.5, 0.5, 0.5)),
+    );
+    for x in [-0.7, -0.3, 0., 0.6, 0.9, 1.1] {
+        let r = Ray::new(Vert4::point(x, 0.1, -5.), Vert4::vector(0., 0., 1.));
+        assert_eq!(grouped.color_at(&r), w.color_at(&r), "at x = {x}");
+    }
+}
+//
+// Shadow tests
+//
+#[test]
+fn there_is_no_shadow_when_nothing_is_collinear_with_point_and_light() {
+    let w = default_world();
+    assert!(!w.is_shadowed(&Vert4::point(0., 10., 0.), &w.lights[0]).unwrap());
+}
+#[test]
+fn the_shadow_when_an_object_is_between_the_point_and_the_light() {
+    let w = default_world();
+    assert!(w.is_shadowed(&Vert4::point(10., -10., 10.), &w.lights[0]).unwrap());
+}
+#[test]
+fn there_is_no_shadow_when_an_object_is_behind_the_light() {
+    let w = default_world();
+    assert!(!w.is_shadowed(&Vert4::point(-20., 20., -20.), &w.lights[0]).unwrap());
+}
+#[test]
+fn there_is_no_shadow_when_an_object_is_behind_the_point() {
+    let w = default_world();
+    assert!(!w.is_shadowed(&Vert4::point(-2., 2., -2.), &w.lights[0]).unwrap());
+}
+#[test]
+fn shadowing_a_vector_fails() {
+    let w = default_world();
+    assert!(w.is_shadowed(&Vert4::vector(10., -10., 10.), &w.lights[0]).is_err());
+}
+#[test]
+fn shade_hit_is_given_an_intersection_in_shadow() {
+    let mut w = World::new();
+    w.add_light(PointLight::new(Vert4::point(0., 0., -10.), Color::WHITE));
+    w.add_object(Sphere::new());
+    w.add_object(Sphere::new().with_transform(Transform::identity().translate(0., 0., 10.)));
+    let r = Ray::new(Vert4::point(0., 0., 5.), Vert4::vector(0., 0., 1.));
+    let i = Intersection::new(4., w.objects[1].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.1, 0.1, 0.1);
+}
+//
+// Reflection tests
+//
+fn reflective_floor(reflective: f32) -> Plane {
+    Plane::new()
+        .with_material(Material { reflective, ..Material::default() })
+        .with_transform(Transform::identity().translate(0., -1., 0.))
+}
+#[test]
+fn the_reflected_color_for_a_nonreflective_material() {
+    let mut w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 0., 1.));
+    w.objects[1].set_material(Material { ambient: 1., ..Material::default() });
+    let i = Intersection::new(1., w.objects[1].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_eq!(w.reflected_color(&comps, 5), Color::BLACK);
+}
+#[test]
+fn the_reflected_color_for_a_reflective_material() {
+    let mut w = default_world();
+    w.add_object(reflective_floor(0.5));
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., -3.), Vert4::vector(0., -k, k));
+    let i = Intersection::new(2f32.sqrt(), w.objects[2].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_color(w.reflected_color(&comps, 5), 0.19032, 0.2379, 0.14274);
+}
+#[test]
+fn shade_hit_with_a_reflective_material() {
+    let mut w = default_world();
+    w.add_object(reflective_floor(0.5));
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., -3.), Vert4::vector(0., -k, k));
+    let i = Intersection::new(2f32.sqrt(), w.objects[2].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.87677, 0.92436, 0.82918);
+}
+#[test]
+fn color_at_with_mutually_reflective_surfaces() {
+    let mut w = World::new();
+    w.add_light(PointLight::new(Vert4::point(0., 0., 0.), Color::WHITE));
+    w.add_object(reflective_floor(1.));
+    w.add_object(
+        Plane::new()
+            .with_material(Material { reflective: 1., ..Material::default() })
+            .with_transform(Transform::identity().translate(0., 1., 0.)),
+    );
+    let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.));
+    // Terminates, with every bounce adding up
+    assert!(w.color_at(&r).r() > 1.);
+}
+#[test]
+fn the_reflected_color_at_the_maximum_recursive_depth() {
+    let mut w = default_world();
+    w.add_object(reflective_floor(0.5));
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., -3.), Vert4::vector(0., -k, k));
+    let i = Intersection::new(2f32.sqrt(), w.objects[2].as_ref());
+    let comps = Computations::prepare(&i, &r, &[i]).unwrap();
+    assert_eq!(w.reflected_color(&comps, 0), Color::BLACK);
+}
+//
+// Refraction tests
+//
+#[test]
+fn the_refracted_color_with_an_opaque_surface() {
+    let w = default_world();
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::new(vec![Intersection::new(4., shape), Intersection::new(6., shape)]);
+    let comps = Computations::prepare(&xs[0], &r, &xs).unwrap();
+    assert_eq!(w.refracted_color(&comps, 5), Color::BLACK);
+}
+#[test]
+fn the_refracted_color_at_the_maximum_recursive_depth() {
+    let mut w = default_world();
+    w.objects[0].set_material(Material { transparency: 1., refractive_index: 1.5, ..Material::default() });
+    let r = Ray::new(Vert4::point(0., 0., -5.), Vert4::vector(0., 0., 1.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::new(vec![Intersection::new(4., shape), Intersection::new(6., shape)]);
+    let comps = Computations::prepare(&xs[0], &r, &xs).unwrap();
+    assert_eq!(w.refracted_color(&comps, 0), Color::BLACK);
+}
+#[test]
+fn the_refracted_color_under_total_internal_reflection() {
+    let mut w = default_world();
+    w.objects[0].set_material(Material { transparency: 1., refractive_index: 1.5, ..Material::default() });
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., k), Vert4::vector(0., 1., 0.));
+    let shape = w.objects[0].as_ref();
+    let xs = Intersections::new(vec![Intersection::new(-k, shape), Intersection::new(k, shape)]);
+    // Inside the sphere, so look at the second intersection
+    let comps = Computations::prepare(&xs[1], &r, &xs).unwrap();
+    assert_eq!(w.refracted_color(&comps, 5), Color::BLACK);
+}
+#[test]
+fn shade_hit_with_a_transparent_material() {
+    let mut w = default_world();
+    w.add_object(
+        Plane::new()
+            .with_material(Material { transparency: 0.5, refractive_index: 1.5, ..Material::default() })
+            .with_transform(Transform::identity().translate(0., -1., 0.)),
+    );
+    w.add_object(
+        Sphere::new()
+            .with_material(Material { color: Color::new(1., 0., 0.), ambient: 0.5, ..Material::default() })
+            .with_transform(Transform::identity().translate(0., -3.5, -0.5)),
+    );
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., -3.), Vert4::vector(0., -k, k));
+    let xs = Intersections::new(vec![Intersection::new(2f32.sqrt(), w.objects[2].as_ref())]);
+    let comps = Computations::prepare(&xs[0], &r, &xs).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.93642, 0.68642, 0.68642);
+}
+#[test]
+fn shade_hit_with_a_reflective_transparent_material() {
+    let mut w = default_world();
+    w.add_object(
+        Plane::new()
+            .with_material(Material {
+                reflective: 0.5,
+                transparency: 0.5,
+                refractive_index: 1.5,
+                ..Material::default()
+            })
+            .with_transform(Transform::identity().translate(0., -1., 0.)),
+    );
+    w.add_object(
+        Sphere::new()
+            .with_material(Material { color: Color::new(1., 0., 0.), ambient: 0.5, ..Material::default() })
+            .with_transform(Transform::identity().translate(0., -3.5, -0.5)),
+    );
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., -3.), Vert4::vector(0., -k, k));
+    let xs = Intersections::new(vec![Intersection::new(2f32.sqrt(), w.objects[2].as_ref())]);
+    let comps = Computations::prepare(&xs[0], &r, &xs).unwrap();
+    assert_color(w.shade_hit(&comps, 5), 0.93391, 0.69643, 0.69243);
+}
+//
+// Schlick tests
+//
+#[test]
+fn the_schlick_approximation_under_total_internal_reflection() {
+    let shape = glass_sphere();
+    let k = 2f32.sqrt() / 2.;
+    let r = Ray::new(Vert4::point(0., 0., k), Vert4::vector(0., 1., 0.));
+    let xs = Intersections::new(vec![Intersection::new(-k, &shape), Intersection::new(k, &shape)]);
+    let comps = Computations::prepare(&xs[1], &r, &xs).unwrap();
+    assert_eq!(comps.schlick(), 1.);
+}
+#[test]
+fn the_schlick_approximation_with_a_perpendicular_viewing_angle() {
+    let shape = glass_sphere();
+    let r = Ray::new(Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.));
+    let xs = Intersections::new(vec![Intersection::new(-1., &shape), Intersection::new(1., &shape)]);
+    let comps = Computations::prepare(&xs[1], &r, &xs).unwrap();
+    assert!((comps.schlick() - 0.04).abs() < EPSILON);
+}
+#[test]
+fn the_schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+    let shape = glass_sphere();
+    let r = Ray::new(Vert4::point(0., 0.99, -2.), Vert4::vector(0., 0., 1.));
+    let xs = Intersections::new(vec![Intersection::new(1.8589, &shape)]);
+    let comps = Computations::prepare(&xs[0], &r, &xs).unwrap();
+    assert!((comps.schlick() - 0.48873).abs() < 1e-3);
+}
+//
+// Render tests
+//
+#[test]
+fn rendering_a_world_with_a_camera() {
+    let w = default_world();
+    let view = Transform::identity().view(Vert4::point(0., 0., -5.), Vert4::point(0., 0., 0.), Vert4::vector(0., 1., 0.));
+    let c = Camera::new(11, 11, FRAC_PI_2).with_transform(view);
+    let image = w.render(&c);
+    assert_color(Color::new(image.pixel_at(5, 5).r(), image.pixel_at(5, 5).g(), image.pixel_at(5, 5).b()), 0.38066, 0.47583, 0.2855);
+}